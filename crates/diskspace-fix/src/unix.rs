@@ -1,4 +1,8 @@
-use std::path::Path;
+use std::ffi::CString;
+use std::fs;
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::fs::MetadataExt;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug)]
 pub struct DiskInfo {
@@ -10,12 +14,274 @@ pub struct DiskInfo {
     pub mounted_on: String,
 }
 
+/// One entry of the mount table, with the kernel's octal escapes decoded.
+#[derive(Debug, Clone)]
+pub struct MountEntry {
+    pub device: String,      // "major:minor" of the mounted device
+    pub source: String,      // e.g. "/dev/sda1", "tmpfs"
+    pub mount_point: String,
+}
+
+/// Returns usage for every mounted filesystem, like `df`.
+/// Pseudo filesystems that report zero blocks (proc, sysfs, cgroup, ...) are skipped.
 pub fn disk_usage() -> Vec<DiskInfo> {
-    // Simple implementation since we just need this to compile
+    read_mounts()
+        .into_iter()
+        .filter_map(|mount| {
+            let path = PathBuf::from(&mount.mount_point);
+            let info = statvfs_info(&path, mount.source, mount.mount_point)?;
+            if info.size == 0 {
+                return None;
+            }
+            Some(info)
+        })
+        .collect()
+}
+
+/// Returns usage for the filesystem containing `path`, or `None` if it can't be stat'ed.
+pub fn disk_usage_on(path: &Path) -> Option<DiskInfo> {
+    let path = path.canonicalize().ok()?;
+    let (filesystem, mounted_on) = match find_mount(&path, &read_mounts()) {
+        Some(mount) => (mount.source.clone(), mount.mount_point.clone()),
+        // No mount table outside Linux: fall back to where the device id changes
+        None => (String::new(), find_mount_point_by_device(&path)?.to_string_lossy().to_string()),
+    };
+    statvfs_info(&path, filesystem, mounted_on)
+}
+
+// The mount containing `path`: the longest mount point that is a prefix of it. Bind mounts and
+// submounts on the same device are told apart this way, unlike by comparing st_dev. On a tie the
+// last entry wins, since a later mount on the same directory shadows the earlier one.
+fn find_mount<'a>(path: &Path, mounts: &'a [MountEntry]) -> Option<&'a MountEntry> {
+    mounts
+        .iter()
+        .filter(|mount| path.starts_with(&mount.mount_point))
+        .max_by_key(|mount| Path::new(&mount.mount_point).components().count())
+}
+
+// Calls statvfs(3) on `path` and converts the result into a DiskInfo
+fn statvfs_info(path: &Path, filesystem: String, mounted_on: String) -> Option<DiskInfo> {
+    let c_path = CString::new(path.as_os_str().as_bytes()).ok()?;
+    let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+    // SAFETY: c_path is a valid NUL-terminated string and stat is a valid out-pointer
+    if unsafe { libc::statvfs(c_path.as_ptr(), &mut stat) } != 0 {
+        return None;
+    }
+
+    let fragment_size = stat.f_frsize as u64;
+    let size = stat.f_blocks as u64 * fragment_size;
+    let free = stat.f_bfree as u64 * fragment_size;
+    let avail = stat.f_bavail as u64 * fragment_size;
+    let used = size.saturating_sub(free);
+
+    // Same formula as df: blocks reserved for root don't count as available to users
+    let use_percent = if used + avail > 0 {
+        (used as f64 / (used + avail) as f64 * 100.0) as f32
+    } else {
+        0.0
+    };
+
+    Some(DiskInfo {
+        filesystem,
+        size,
+        used,
+        avail,
+        use_percent,
+        mounted_on,
+    })
+}
+
+// Walks up from `path` until the device id changes; the last directory on the
+// original device is its mount point.
+fn find_mount_point_by_device(path: &Path) -> Option<PathBuf> {
+    let dev = fs::metadata(path).ok()?.dev();
+    let mut current = path;
+    while let Some(parent) = current.parent() {
+        match fs::metadata(parent) {
+            Ok(meta) if meta.dev() == dev => current = parent,
+            _ => break,
+        }
+    }
+    Some(current.to_path_buf())
+}
+
+/// Every entry of /proc/self/mountinfo, in mount order (empty outside Linux).
+#[cfg(target_os = "linux")]
+pub fn read_mounts() -> Vec<MountEntry> {
+    match fs::read_to_string("/proc/self/mountinfo") {
+        Ok(content) => content.lines().filter_map(parse_mountinfo_line).collect(),
+        Err(_) => Vec::new(),
+    }
+}
+
+// No /proc/self/mountinfo outside Linux
+#[cfg(not(target_os = "linux"))]
+pub fn read_mounts() -> Vec<MountEntry> {
     Vec::new()
 }
 
-pub fn disk_usage_on(_path: &Path) -> Option<DiskInfo> {
-    // Simple implementation since we just need this to compile
-    None
-}
\ No newline at end of file
+/// Parses one line of /proc/self/mountinfo, e.g.
+/// `36 35 98:0 /mnt1 /mnt/parent rw,noatime master:1 - ext3 /dev/root rw,errors=continue`
+pub fn parse_mountinfo_line(line: &str) -> Option<MountEntry> {
+    let (pre, post) = line.split_once(" - ")?;
+    let mut pre_fields = pre.split_whitespace();
+    let device = pre_fields.nth(2)?;
+    let mount_point = pre_fields.nth(1)?;
+    let source = post.split_whitespace().nth(1)?;
+    Some(MountEntry {
+        device: device.to_string(),
+        source: unescape_octal(source),
+        mount_point: unescape_octal(mount_point),
+    })
+}
+
+/// Decodes the `\040`-style escapes the kernel uses for spaces, tabs, newlines and backslashes.
+pub fn unescape_octal(raw: &str) -> String {
+    let bytes = raw.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'\\'
+            && i + 3 < bytes.len()
+            && bytes[i + 1..i + 4].iter().all(|b| (b'0'..=b'7').contains(b))
+        {
+            let value = bytes[i + 1..i + 4]
+                .iter()
+                .fold(0u16, |acc, b| acc * 8 + (b - b'0') as u16);
+            out.push(value as u8);
+            i += 4;
+        } else {
+            out.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8_lossy(&out).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    // Creates a unique scratch directory under the system temp dir
+    fn scratch_dir(tag: &str) -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!(
+            "diskspace-{}-{}-{}",
+            tag,
+            std::process::id(),
+            nanos
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn disk_usage_on_temp_dir_reports_consistent_sizes() {
+        let dir = scratch_dir("usage");
+        let info = disk_usage_on(&dir).expect("temp dir should be stat-able");
+
+        assert!(info.size > 0);
+        assert!(info.used <= info.size);
+        assert!(info.avail <= info.size);
+        assert!((0.0..=100.0).contains(&info.use_percent));
+        assert!(dir.canonicalize().unwrap().starts_with(&info.mounted_on));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn disk_usage_on_file_matches_parent_dir() {
+        let dir = scratch_dir("file");
+        let file = dir.join("data.bin");
+        fs::write(&file, vec![0u8; 4096]).unwrap();
+
+        let for_file = disk_usage_on(&file).unwrap();
+        let for_dir = disk_usage_on(&dir).unwrap();
+        assert_eq!(for_file.mounted_on, for_dir.mounted_on);
+        assert_eq!(for_file.size, for_dir.size);
+        assert_eq!(for_file.filesystem, for_dir.filesystem);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn disk_usage_on_missing_path_is_none() {
+        let dir = scratch_dir("missing");
+        assert!(disk_usage_on(&dir.join("does-not-exist")).is_none());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn disk_usage_includes_temp_dir_mount() {
+        let dir = scratch_dir("list");
+        let on_dir = disk_usage_on(&dir).unwrap();
+        let all = disk_usage();
+
+        assert!(!all.is_empty());
+        assert!(all.iter().all(|info| info.size > 0));
+        assert!(all.iter().any(|info| info.mounted_on == on_dir.mounted_on));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn parses_mountinfo_line_with_escapes() {
+        let line = "36 35 98:0 / /mnt/my\\040disk rw,noatime master:1 - ext4 /dev/sdb1 rw,errors=continue";
+        let entry = parse_mountinfo_line(line).unwrap();
+        assert_eq!(entry.device, "98:0");
+        assert_eq!(entry.mount_point, "/mnt/my disk");
+        assert_eq!(entry.source, "/dev/sdb1");
+    }
+
+    #[test]
+    fn parses_mountinfo_line_with_optional_fields_missing() {
+        let line = "22 1 0:21 / /proc rw,nosuid - proc proc rw";
+        let entry = parse_mountinfo_line(line).unwrap();
+        assert_eq!(entry.mount_point, "/proc");
+        assert_eq!(entry.source, "proc");
+        assert!(parse_mountinfo_line("garbage").is_none());
+    }
+
+    fn mount(device: &str, source: &str, mount_point: &str) -> MountEntry {
+        MountEntry {
+            device: device.to_string(),
+            source: source.to_string(),
+            mount_point: mount_point.to_string(),
+        }
+    }
+
+    #[test]
+    fn find_mount_picks_longest_prefix() {
+        let mounts = [
+            mount("8:1", "/dev/sda1", "/"),
+            mount("8:1", "/dev/sda1", "/srv/data"), // Bind mount from the same device
+            mount("8:2", "/dev/sda2", "/home"),
+            mount("0:40", "tmpfs", "/home/user/cache"),
+        ];
+        let find = |path: &str| find_mount(Path::new(path), &mounts).map(|m| m.mount_point.as_str());
+        assert_eq!(find("/srv/data/file"), Some("/srv/data"));
+        assert_eq!(find("/srv/database"), Some("/")); // Component-wise, not a string prefix
+        assert_eq!(find("/home/user/cache/x"), Some("/home/user/cache"));
+        assert_eq!(find("/home/user"), Some("/home"));
+        assert_eq!(find("/etc"), Some("/"));
+    }
+
+    #[test]
+    fn find_mount_prefers_the_last_mount_on_a_directory() {
+        let mounts = [mount("8:1", "/dev/sda1", "/"), mount("8:3", "/dev/sdb1", "/mnt"), mount("0:50", "tmpfs", "/mnt")];
+        assert_eq!(find_mount(Path::new("/mnt/a"), &mounts).unwrap().source, "tmpfs");
+    }
+
+    #[test]
+    fn unescape_leaves_plain_and_invalid_sequences_alone() {
+        assert_eq!(unescape_octal("/plain/path"), "/plain/path");
+        assert_eq!(unescape_octal("a\\134b"), "a\\b");
+        assert_eq!(unescape_octal("a\\09"), "a\\09");
+        assert_eq!(unescape_octal("tail\\04"), "tail\\04");
+    }
+}
@@ -0,0 +1,185 @@
+// TauriViteReact/src-tauri/src/alerts/engine.rs
+// Evaluates alert rules against every snapshot and tracks pending/firing state
+// per (rule, label), so "any core" rules fire and resolve for each core independently.
+
+use super::rules::{AlertRule, Comparison, Severity};
+use crate::models::SystemData;
+use crate::storage::flatten_samples;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+
+/// One firing (or just resolved) alert; payload of the `alert-fired`/`alert-resolved` events.
+#[derive(Serialize, Clone, Debug)]
+pub struct Alert {
+    pub rule_id: String,
+    pub description: String,
+    pub metric: String,
+    pub label: String, // Empty for single-series metrics
+    pub severity: Severity,
+    pub comparison: Comparison,
+    pub threshold: f64,
+    pub value: f64,                   // Latest value while firing, last breaching value once resolved
+    pub since_ms: i64,                // When the condition first became true
+    pub fired_at_ms: i64,             // When the sustained duration was reached
+    pub resolved_at_ms: Option<i64>,
+}
+
+#[derive(Debug, Clone)]
+pub enum AlertEvent {
+    Fired(Alert),
+    Resolved(Alert),
+}
+
+// State of one (rule, label) pair whose condition is currently true
+struct Instance {
+    since_ms: i64,
+    fired_at_ms: Option<i64>, // Some once firing
+    value: f64,
+}
+
+pub struct AlertEngine {
+    enabled: bool,
+    rules: Vec<AlertRule>,
+    instances: HashMap<(String, String), Instance>,
+}
+
+impl AlertEngine {
+    /// Invalid rules are logged and skipped rather than failing startup.
+    pub fn new(enabled: bool, rules: Vec<AlertRule>) -> Self {
+        let rules = rules
+            .into_iter()
+            .filter(|rule| match rule.validate() {
+                Ok(()) => true,
+                Err(e) => {
+                    log::warn!("Ignoring alert rule: {}", e);
+                    false
+                }
+            })
+            .collect();
+        AlertEngine { enabled, rules, instances: HashMap::new() }
+    }
+
+    pub fn rules(&self) -> &[AlertRule] {
+        &self.rules
+    }
+
+    /// Adds a rule, or replaces the rule with the same id.
+    pub fn upsert_rule(&mut self, rule: AlertRule) -> Result<(), String> {
+        rule.validate()?;
+        match self.rules.iter_mut().find(|r| r.id == rule.id) {
+            Some(existing) => *existing = rule,
+            None => self.rules.push(rule),
+        }
+        Ok(())
+    }
+
+    /// Removes a rule; its firing alerts resolve on the next evaluation. Returns false for unknown ids.
+    pub fn remove_rule(&mut self, id: &str) -> bool {
+        let before = self.rules.len();
+        self.rules.retain(|r| r.id != id);
+        self.rules.len() != before
+    }
+
+    pub fn set_rule_enabled(&mut self, id: &str, enabled: bool) -> bool {
+        match self.rules.iter_mut().find(|r| r.id == id) {
+            Some(rule) => {
+                rule.enabled = enabled;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Currently firing alerts, most severe first.
+    pub fn active_alerts(&self) -> Vec<Alert> {
+        let mut alerts: Vec<Alert> = self
+            .instances
+            .iter()
+            .filter_map(|((rule_id, label), instance)| {
+                let rule = self.rules.iter().find(|r| &r.id == rule_id)?;
+                instance.fired_at_ms.map(|fired_at_ms| to_alert(rule, label, instance, fired_at_ms, None))
+            })
+            .collect();
+        alerts.sort_by(|a, b| b.severity.cmp(&a.severity).then(a.fired_at_ms.cmp(&b.fired_at_ms)));
+        alerts
+    }
+
+    /// Evaluates every enabled rule against a snapshot and returns the state transitions.
+    /// Series that disappear (unplugged disk, removed rule) resolve as well.
+    pub fn evaluate(&mut self, data: &SystemData) -> Vec<AlertEvent> {
+        if !self.enabled {
+            return Vec::new();
+        }
+        let now_ms = data.timestamp.timestamp_millis();
+        let samples = flatten_samples(data);
+        let mut events = Vec::new();
+        let mut breaching: HashSet<(String, String)> = HashSet::new();
+
+        for rule in self.rules.iter().filter(|r| r.enabled) {
+            let matching = samples.iter().filter(|(metric, label, value)| {
+                *metric == rule.metric && value.is_finite() && rule.label.as_ref().is_none_or(|l| l == label)
+            });
+            for (_, label, value) in matching {
+                if !rule.matches(*value) {
+                    continue; // Cleared instances are resolved below
+                }
+                let key = (rule.id.clone(), label.clone());
+                let instance = self
+                    .instances
+                    .entry(key.clone())
+                    .or_insert(Instance { since_ms: now_ms, fired_at_ms: None, value: *value });
+                instance.value = *value;
+                if instance.fired_at_ms.is_none() && now_ms - instance.since_ms >= rule.for_secs as i64 * 1000 {
+                    instance.fired_at_ms = Some(now_ms);
+                    log::warn!("Alert '{}' fired for '{}': {} = {:.1}", rule.id, label, rule.metric, value);
+                    events.push(AlertEvent::Fired(to_alert(rule, label, instance, now_ms, None)));
+                }
+                breaching.insert(key);
+            }
+        }
+
+        // Everything not breaching this cycle is dropped; firing ones emit a resolution
+        let cleared: Vec<(String, String)> =
+            self.instances.keys().filter(|key| !breaching.contains(*key)).cloned().collect();
+        for key in cleared {
+            let Some(instance) = self.instances.remove(&key) else { continue };
+            let Some(fired_at_ms) = instance.fired_at_ms else { continue };
+            log::info!("Alert '{}' resolved for '{}'", key.0, key.1);
+            let alert = match self.rules.iter().find(|r| r.id == key.0) {
+                Some(rule) => to_alert(rule, &key.1, &instance, fired_at_ms, Some(now_ms)),
+                // Rule was deleted meanwhile; report what is still known
+                None => Alert {
+                    rule_id: key.0.clone(),
+                    description: String::new(),
+                    metric: String::new(),
+                    label: key.1.clone(),
+                    severity: Severity::default(),
+                    comparison: Comparison::default(),
+                    threshold: f64::NAN,
+                    value: instance.value,
+                    since_ms: instance.since_ms,
+                    fired_at_ms,
+                    resolved_at_ms: Some(now_ms),
+                },
+            };
+            events.push(AlertEvent::Resolved(alert));
+        }
+        events
+    }
+}
+
+fn to_alert(rule: &AlertRule, label: &str, instance: &Instance, fired_at_ms: i64, resolved_at_ms: Option<i64>) -> Alert {
+    Alert {
+        rule_id: rule.id.clone(),
+        description: rule.description.clone(),
+        metric: rule.metric.clone(),
+        label: label.to_string(),
+        severity: rule.severity,
+        comparison: rule.comparison,
+        threshold: rule.threshold,
+        value: instance.value,
+        since_ms: instance.since_ms,
+        fired_at_ms,
+        resolved_at_ms,
+    }
+}
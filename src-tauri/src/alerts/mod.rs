@@ -0,0 +1,8 @@
+// TauriViteReact/src-tauri/src/alerts/mod.rs
+
+// Threshold alerting on top of the flattened metrics used by the history store
+pub mod engine;
+pub mod rules;
+
+pub use engine::{Alert, AlertEngine, AlertEvent};
+pub use rules::{default_rules, AlertRule, Comparison, Severity};
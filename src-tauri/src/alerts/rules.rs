@@ -0,0 +1,90 @@
+// TauriViteReact/src-tauri/src/alerts/rules.rs
+// Alert rule definitions, as stored in config.json and edited through commands.
+
+use crate::storage;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Comparison {
+    #[default]
+    Above,
+    Below,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Info,
+    #[default]
+    Warning,
+    Critical,
+}
+
+/// "`metric` (for `label`, or any label) is above/below `threshold` for at least `for_secs`".
+/// Metric names and units are the ones used by the history store (see `storage::METRICS`).
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct AlertRule {
+    pub id: String,
+    #[serde(default)]
+    pub description: String,
+    pub metric: String,
+    #[serde(default)]
+    pub label: Option<String>, // None: every label (core, interface, mount point, GPU) is checked separately
+    #[serde(default)]
+    pub comparison: Comparison,
+    pub threshold: f64,
+    #[serde(default)]
+    pub for_secs: u64, // 0 fires on the first matching sample
+    #[serde(default)]
+    pub severity: Severity,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+impl AlertRule {
+    pub fn matches(&self, value: f64) -> bool {
+        match self.comparison {
+            Comparison::Above => value > self.threshold,
+            Comparison::Below => value < self.threshold,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.id.trim().is_empty() {
+            return Err("Alert rule id must not be empty".to_string());
+        }
+        if storage::metric(&self.metric).is_none() {
+            return Err(format!("Unknown metric '{}' in alert rule '{}'", self.metric, self.id));
+        }
+        if !self.threshold.is_finite() {
+            return Err(format!("Threshold of alert rule '{}' must be a finite number", self.id));
+        }
+        Ok(())
+    }
+}
+
+/// Rules used when config.json has no `alerts` section.
+pub fn default_rules() -> Vec<AlertRule> {
+    let rule = |id: &str, description: &str, metric: &str, threshold: f64, for_secs: u64, severity: Severity| AlertRule {
+        id: id.to_string(),
+        description: description.to_string(),
+        metric: metric.to_string(),
+        label: None,
+        comparison: Comparison::Above,
+        threshold,
+        for_secs,
+        severity,
+        enabled: true,
+    };
+    vec![
+        rule("cpu_core_high", "CPU core above 95% for 30 s", "cpu_usage", 95.0, 30, Severity::Warning),
+        rule("memory_high", "Memory usage above 90%", "memory_percent", 90.0, 0, Severity::Warning),
+        rule("disk_almost_full", "Filesystem more than 95% full", "disk_used_percent", 95.0, 0, Severity::Critical),
+        rule("gpu_hot", "GPU temperature above 85 °C", "gpu_temperature", 85.0, 0, Severity::Critical),
+    ]
+}
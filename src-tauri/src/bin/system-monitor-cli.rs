@@ -0,0 +1,377 @@
+// TauriViteReact/src-tauri/src/bin/system-monitor-cli.rs
+// Headless front-end: same collectors as the GUI, no Tauri window.
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::process::ExitCode;
+use system_monitor_pro_lib::{
+    collect_all_system_data_structured, DEFAULT_UPDATE_INTERVAL_MS, MAX_UPDATE_INTERVAL_MS, MIN_UPDATE_INTERVAL_MS,
+};
+use system_monitor_pro_lib::exporters::file::{self, ExportFormat};
+use system_monitor_pro_lib::exporters::prometheus::{self, MetricsHandle};
+use system_monitor_pro_lib::models::{AppStateInner, SystemData};
+use system_monitor_pro_lib::utils::{format_speed, MonitorError};
+
+const USAGE: &str = "\
+Usage: system-monitor-cli [OPTIONS]
+
+Options:
+  -f, --format <FORMAT>      Output format: json (one object per line) or table;
+                             json or csv with --export (default: json)
+  -i, --interval <MS>        Sampling interval in milliseconds, 250-60000 (default: 1000)
+  -n, --count <N>            Stop after N samples (default: run until interrupted,
+                             or 1 with --export)
+      --once                 Print a single snapshot and exit
+      --export <WHAT>        Collect silently, then write the last snapshot or one of its
+                             history series and exit. WHAT is \"snapshot\" or a series id
+                             such as cpu_history or network:eth0 (histories keep up to
+                             100 samples, so use -n to fill them)
+      --list-series          Collect once and list the series ids --export accepts
+  -o, --output <FILE>        Write the export to FILE instead of stdout
+      --metrics <ADDR>       Also serve Prometheus metrics at http://ADDR/metrics
+                             (e.g. 127.0.0.1:9184)
+  -h, --help                 Show this help";
+
+// Consecutive collection errors before the sampling loop exits instead of retrying forever
+const MAX_CONSECUTIVE_FAILURES: u32 = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum OutputFormat {
+    Json,
+    Table,
+    Csv, // Only for exports
+}
+
+// What the sampling loop can print; csv only exists as an export
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PrintFormat {
+    Json,
+    Table,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Export {
+    Snapshot,
+    History(String),
+    ListSeries,
+}
+
+#[derive(Debug)]
+struct CliOptions {
+    format: OutputFormat,
+    interval_ms: u64,
+    count: Option<u64>,
+    metrics_address: Option<String>,
+    export: Option<Export>,
+    output: Option<String>,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Option<CliOptions>, String> {
+    let mut options = CliOptions {
+        format: OutputFormat::Json,
+        interval_ms: DEFAULT_UPDATE_INTERVAL_MS,
+        count: None,
+        metrics_address: None,
+        export: None,
+        output: None,
+    };
+
+    while let Some(arg) = args.next() {
+        // Accept both "--flag value" and "--flag=value"
+        let (flag, inline_value) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => (flag.to_string(), Some(value.to_string())),
+            _ => (arg.clone(), None),
+        };
+        let mut value = |name: &str| -> Result<String, String> {
+            inline_value.clone().or_else(|| args.next()).ok_or_else(|| format!("Missing value for {}", name))
+        };
+
+        match flag.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "-f" | "--format" => {
+                options.format = match value(&flag)?.as_str() {
+                    "json" => OutputFormat::Json,
+                    "table" => OutputFormat::Table,
+                    "csv" => OutputFormat::Csv,
+                    other => return Err(format!("Unknown format '{}' (expected json, table or csv)", other)),
+                };
+            }
+            "-i" | "--interval" => {
+                let raw = value(&flag)?;
+                let ms = raw.parse::<u64>().map_err(|_| format!("Invalid interval '{}'", raw))?;
+                if !(MIN_UPDATE_INTERVAL_MS..=MAX_UPDATE_INTERVAL_MS).contains(&ms) {
+                    return Err(format!(
+                        "Interval {} ms is out of range ({}-{})",
+                        ms, MIN_UPDATE_INTERVAL_MS, MAX_UPDATE_INTERVAL_MS
+                    ));
+                }
+                options.interval_ms = ms;
+            }
+            "-n" | "--count" => {
+                let raw = value(&flag)?;
+                options.count = Some(raw.parse::<u64>().map_err(|_| format!("Invalid count '{}'", raw))?);
+            }
+            "--once" => options.count = Some(1),
+            "--metrics" => options.metrics_address = Some(value(&flag)?),
+            "--export" => {
+                options.export = Some(match value(&flag)?.as_str() {
+                    "snapshot" => Export::Snapshot,
+                    series => Export::History(series.to_string()),
+                });
+            }
+            "--list-series" => options.export = Some(Export::ListSeries),
+            "-o" | "--output" => options.output = Some(value(&flag)?),
+            other => return Err(format!("Unknown argument '{}'", other)),
+        }
+    }
+
+    match (&options.export, options.format) {
+        (Some(_), OutputFormat::Table) => return Err("--export writes json or csv, not table".to_string()),
+        (None, OutputFormat::Csv) => return Err("csv output needs --export".to_string()),
+        (None, _) if options.output.is_some() => return Err("--output needs --export".to_string()),
+        (Some(_), _) if options.count.is_none() => options.count = Some(1),
+        _ => {}
+    }
+    Ok(Some(options))
+}
+
+fn print_table_header(out: &mut impl Write) -> std::io::Result<()> {
+    writeln!(
+        out,
+        "{:<12} {:>6} {:>6} {:>17} {:>12} {:>12} {:>12} {:>12} {:>6}",
+        "TIME", "CPU%", "LOAD1", "MEM (GB)", "NET RX", "NET TX", "DISK R", "DISK W", "GPU%"
+    )
+}
+
+fn print_table_row(out: &mut impl Write, data: &SystemData) -> std::io::Result<()> {
+    let cpu_avg = if data.cpu_usage.is_empty() {
+        0.0
+    } else {
+        data.cpu_usage.iter().sum::<f64>() / data.cpu_usage.len() as f64
+    };
+    let mem_percent = if data.memory_total > 0.0 { data.memory_used / data.memory_total * 100.0 } else { 0.0 };
+    // fold from +0.0: an empty f64 sum() is -0.0 and would print as "-0.0 KB/s"
+    let rx = data.network_data.values().fold(0.0, |acc, n| acc + n.current_rx_speed);
+    let tx = data.network_data.values().fold(0.0, |acc, n| acc + n.current_tx_speed);
+    let gpu = data
+        .gpu_data
+        .iter()
+        .map(|g| g.utilization)
+        .fold(None, |max: Option<f64>, v| Some(max.map_or(v, |m| m.max(v))));
+
+    writeln!(
+        out,
+        "{:<12} {:>6.1} {:>6.2} {:>17} {:>12} {:>12} {:>12} {:>12} {:>6}",
+        data.timestamp.format("%H:%M:%S%.3f"),
+        cpu_avg,
+        data.cpu_info.load_average[0],
+        format!("{:.1}/{:.1} ({:.0}%)", data.memory_used, data.memory_total, mem_percent),
+        format_speed(rx),
+        format_speed(tx),
+        format_speed(data.system_disk_read_per_sec),
+        format_speed(data.system_disk_write_per_sec),
+        gpu.map_or("-".to_string(), |g| format!("{:.1}", g)),
+    )
+}
+
+fn emit(out: &mut impl Write, format: PrintFormat, data: &SystemData) -> std::io::Result<()> {
+    match format {
+        PrintFormat::Json => {
+            serde_json::to_writer(&mut *out, data)?;
+            writeln!(out)?;
+        }
+        PrintFormat::Table => print_table_row(out, data)?,
+    }
+    out.flush()
+}
+
+// Writes the requested export of the last snapshot to --output or stdout
+fn export(options: &CliOptions, what: &Export, data: &SystemData) -> Result<(), String> {
+    let format = if options.format == OutputFormat::Csv { ExportFormat::Csv } else { ExportFormat::Json };
+    let mut out: Box<dyn Write> = match &options.output {
+        Some(path) => Box::new(BufWriter::new(File::create(path).map_err(|e| format!("Failed to create {}: {}", path, e))?)),
+        None => Box::new(std::io::stdout().lock()),
+    };
+    let written = match what {
+        Export::Snapshot => file::write_snapshot(&mut out, data, format),
+        Export::History(series) => {
+            let table = file::history_table(data, series).map_err(|e| format!("{} (see --list-series)", e))?;
+            file::write_history(&mut out, &table, format)
+        }
+        Export::ListSeries => {
+            for series in file::list_history_series(data) {
+                if let Err(e) = writeln!(out, "{:<32} {:<40} {}", series.id, series.title, series.columns.join(", ")) {
+                    return handle_write_error(e);
+                }
+            }
+            Ok(())
+        }
+    };
+    match written.map_err(|e| match e {
+        MonitorError::IoError(e) => e,
+        other => std::io::Error::other(other.to_string()),
+    }).and_then(|_| out.flush()) {
+        Ok(()) => Ok(()),
+        Err(e) => handle_write_error(e),
+    }
+}
+
+async fn run_loop(options: CliOptions) -> Result<(), String> {
+    let mut state = AppStateInner { update_interval_ms: options.interval_ms, ..Default::default() };
+    let interval_duration = tokio::time::Duration::from_millis(options.interval_ms);
+
+    let metrics = options.metrics_address.clone().map(|listen_address| {
+        let handle = MetricsHandle::new();
+        let server_handle = handle.clone();
+        tokio::spawn(async move {
+            if let Err(e) = prometheus::serve(listen_address, server_handle).await {
+                log::error!("Prometheus metrics endpoint stopped: {}", e);
+            }
+        });
+        handle
+    });
+
+    // CPU usage and all rates are deltas, so take a silent baseline sample first
+    collect_all_system_data_structured(&mut state).map_err(|e| format!("Initial collection failed: {}", e))?;
+    tokio::time::sleep(interval_duration).await;
+
+    if let Some(to_export) = options.export.clone() {
+        return export_after_samples(&options, &to_export, &mut state, interval_duration, metrics.as_ref()).await;
+    }
+
+    let format = match options.format {
+        OutputFormat::Json => PrintFormat::Json,
+        OutputFormat::Table => PrintFormat::Table,
+        OutputFormat::Csv => return Err("csv output needs --export".to_string()),
+    };
+    let stdout = std::io::stdout();
+    let mut out = stdout.lock();
+    if format == PrintFormat::Table
+        && let Err(e) = print_table_header(&mut out)
+    {
+        return handle_write_error(e);
+    }
+
+    let mut interval = tokio::time::interval(interval_duration);
+    let mut emitted: u64 = 0;
+    let mut failures = 0;
+    while options.count.is_none_or(|count| emitted < count) {
+        interval.tick().await;
+        match collect_cycle(&mut state) {
+            Ok(data) => {
+                failures = 0;
+                if let Some(metrics) = &metrics {
+                    metrics.update(&data);
+                }
+                if let Err(e) = emit(&mut out, format, &data) {
+                    return handle_write_error(e);
+                }
+                emitted += 1;
+            }
+            Err(e) => record_failure(&mut failures, e)?,
+        }
+    }
+    Ok(())
+}
+
+// --export: collect --count samples without printing them, then export the last one
+async fn export_after_samples(
+    options: &CliOptions,
+    to_export: &Export,
+    state: &mut AppStateInner,
+    interval_duration: tokio::time::Duration,
+    metrics: Option<&MetricsHandle>,
+) -> Result<(), String> {
+    let count = options.count.unwrap_or(1);
+    let mut interval = tokio::time::interval(interval_duration);
+    let mut last = None;
+    let mut collected: u64 = 0;
+    let mut failures = 0;
+    while collected < count {
+        interval.tick().await;
+        match collect_cycle(state) {
+            Ok(data) => {
+                failures = 0;
+                if let Some(metrics) = metrics {
+                    metrics.update(&data);
+                }
+                last = Some(data);
+                collected += 1;
+            }
+            Err(e) => record_failure(&mut failures, e)?,
+        }
+    }
+    match last {
+        Some(data) => export(options, to_export, &data),
+        None => Ok(()), // --count 0
+    }
+}
+
+// One collection cycle. Collector errors only end up in the registry statuses, so a cycle
+// in which every enabled collector failed is turned into an error here.
+fn collect_cycle(state: &mut AppStateInner) -> Result<SystemData, String> {
+    let data = collect_all_system_data_structured(state).map_err(|e| e.to_string())?;
+    let enabled: Vec<_> = state.collectors.statuses().into_iter().filter(|status| status.enabled).collect();
+    if !enabled.is_empty() && enabled.iter().all(|status| status.last_error.is_some()) {
+        let errors: Vec<String> = enabled
+            .iter()
+            .map(|status| format!("{}: {}", status.name, status.last_error.as_deref().unwrap_or_default()))
+            .collect();
+        return Err(format!("Every collector failed ({})", errors.join("; ")));
+    }
+    Ok(data)
+}
+
+// Logs a failed collection and gives up once MAX_CONSECUTIVE_FAILURES happen in a row
+fn record_failure(failures: &mut u32, e: impl std::fmt::Display) -> Result<(), String> {
+    log::error!("Error collecting system data: {}", e);
+    *failures += 1;
+    if *failures >= MAX_CONSECUTIVE_FAILURES {
+        return Err(format!("Giving up after {} failed collections in a row: {}", failures, e));
+    }
+    Ok(())
+}
+
+// A closed pipe (e.g. `| head`) is a normal way to stop the stream, not an error
+fn handle_write_error(e: std::io::Error) -> Result<(), String> {
+    if e.kind() == std::io::ErrorKind::BrokenPipe {
+        Ok(())
+    } else {
+        Err(format!("Failed to write output: {}", e))
+    }
+}
+
+fn main() -> ExitCode {
+    // Logs go to stderr so stdout stays machine-readable
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("warn"))
+        .format_timestamp_millis()
+        .init();
+
+    let options = match parse_args(std::env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    log::info!("Starting headless collection: {:?}", options);
+
+    let rt = match tokio::runtime::Builder::new_current_thread().enable_all().build() {
+        Ok(rt) => rt,
+        Err(e) => {
+            eprintln!("Failed to create Tokio runtime: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    match rt.block_on(run_loop(options)) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
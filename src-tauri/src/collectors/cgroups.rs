@@ -0,0 +1,293 @@
+// TauriViteReact/src-tauri/src/collectors/cgroups.rs
+// cgroup v2 accounting: CPU, memory, I/O and pid counts for every cgroup under the unified
+// hierarchy, with containers recognised from the path layouts docker, podman and kubelet use
+// and systemd services/scopes recognised from their unit names.
+
+use crate::collectors::registry::{CollectContext, Collector};
+use crate::models::{CgroupInfo, ContainerRef, SystemData, SystemdUnit};
+use crate::utils::error::Result;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+pub const CGROUP_ROOT: &str = "/sys/fs/cgroup";
+
+const BYTES_PER_MB: f64 = 1024.0 * 1024.0;
+
+// Cumulative counters needed for rates
+#[derive(Clone, Copy, Debug, Default)]
+struct CgroupCounters {
+    cpu_usage_usec: u64,
+    io_read_bytes: u64,
+    io_write_bytes: u64,
+}
+
+/// Per-cgroup usage; counters from the previous cycle are kept per path
+#[derive(Default)]
+pub struct CgroupsCollector {
+    last: HashMap<String, (CgroupCounters, Instant)>,
+}
+
+impl Collector for CgroupsCollector {
+    fn name(&self) -> &'static str { "cgroups" }
+
+    // Reads cgroupfs directly, no sysinfo refresh needed
+    fn collect(&mut self, ctx: &CollectContext<'_>, data: &mut SystemData) -> Result<()> {
+        let Some(root) = unified_root(Path::new(CGROUP_ROOT)) else {
+            data.cgroups.clear();
+            data.systemd_units.clear();
+            return Ok(());
+        };
+
+        let mut cgroups = Vec::new();
+        let mut units = Vec::new();
+        let mut current = HashMap::new();
+        for (path, dir) in walk_cgroups(&root) {
+            let counters = read_counters(&dir);
+            let mut info = read_cgroup(&path, &dir);
+            if let Some((previous, at)) = self.last.get(&path) {
+                let elapsed = ctx.now.duration_since(*at).as_secs_f64();
+                if elapsed > 0.001 {
+                    // Counters reset when a cgroup is removed and recreated under the same path
+                    let per_sec = |now: u64, before: u64| now.saturating_sub(before) as f64 / elapsed;
+                    info.cpu_usage = per_sec(counters.cpu_usage_usec, previous.cpu_usage_usec) / 1_000_000.0 * 100.0;
+                    info.io_read_kbps = per_sec(counters.io_read_bytes, previous.io_read_bytes) / 1024.0;
+                    info.io_write_kbps = per_sec(counters.io_write_bytes, previous.io_write_bytes) / 1024.0;
+                }
+            }
+            if let Some(unit_type) = unit_type(&info.name) {
+                units.push(systemd_unit(&info, unit_type, &dir));
+            }
+            current.insert(path, (counters, ctx.now));
+            cgroups.push(info);
+        }
+        // Also forgets cgroups that went away
+        self.last = current;
+
+        for unit in &mut units {
+            unit.process_count = cgroups.iter().filter(|c| is_within(&c.path, &unit.cgroup_path)).map(|c| c.process_count).sum();
+        }
+        data.cgroups = cgroups;
+        data.systemd_units = units;
+        Ok(())
+    }
+
+    fn reset_state(&mut self) { self.last.clear(); }
+}
+
+// --- Hierarchy ---
+
+/// The cgroup v2 mount: `root` itself on unified systems, `root/unified` on hybrid (v1 + v2) ones.
+pub fn unified_root(root: &Path) -> Option<PathBuf> {
+    [root.to_path_buf(), root.join("unified")].into_iter().find(|dir| dir.join("cgroup.controllers").is_file())
+}
+
+// Every cgroup below `root` as ("/relative/path", absolute dir), parents before children
+fn walk_cgroups(root: &Path) -> Vec<(String, PathBuf)> {
+    let mut found = Vec::new();
+    let mut pending = vec![(String::from("/"), root.to_path_buf())];
+    while let Some((path, dir)) = pending.pop() {
+        let Ok(entries) = fs::read_dir(&dir) else { continue };
+        let mut children: Vec<(String, PathBuf)> = entries
+            .flatten()
+            .filter(|entry| entry.file_type().map(|t| t.is_dir()).unwrap_or(false))
+            .map(|entry| {
+                let name = entry.file_name().to_string_lossy().into_owned();
+                let child_path = if path == "/" { format!("/{}", name) } else { format!("{}/{}", path, name) };
+                (child_path, entry.path())
+            })
+            .collect();
+        // Reversed so the stack pops them in name order
+        children.sort_by(|a, b| b.0.cmp(&a.0));
+        found.push((path, dir));
+        pending.extend(children);
+    }
+    found
+}
+
+fn read_trimmed(path: &Path) -> Option<String> {
+    fs::read_to_string(path).ok().map(|s| s.trim().to_string())
+}
+
+// Reads "key value" lines (cpu.stat, memory.stat) for one key
+fn read_keyed(path: &Path, key: &str) -> Option<u64> {
+    fs::read_to_string(path)
+        .ok()?
+        .lines()
+        .find_map(|line| line.strip_prefix(key)?.strip_prefix(' ')?.trim().parse().ok())
+}
+
+fn read_counters(dir: &Path) -> CgroupCounters {
+    let (io_read_bytes, io_write_bytes) = read_io_stat(dir);
+    CgroupCounters { cpu_usage_usec: read_keyed(&dir.join("cpu.stat"), "usage_usec").unwrap_or(0), io_read_bytes, io_write_bytes }
+}
+
+// io.stat: one "MAJ:MIN rbytes=.. wbytes=.. rios=.. wios=.." line per device, summed here.
+// Only present once the io controller is enabled for the parent.
+fn read_io_stat(dir: &Path) -> (u64, u64) {
+    let Ok(content) = fs::read_to_string(dir.join("io.stat")) else { return (0, 0) };
+    let mut totals = (0, 0);
+    for field in content.split_whitespace() {
+        if let Some(value) = field.strip_prefix("rbytes=") {
+            totals.0 += value.parse::<u64>().unwrap_or(0);
+        } else if let Some(value) = field.strip_prefix("wbytes=") {
+            totals.1 += value.parse::<u64>().unwrap_or(0);
+        }
+    }
+    totals
+}
+
+fn read_cgroup(path: &str, dir: &Path) -> CgroupInfo {
+    let bytes_to_mb = |bytes: u64| bytes as f64 / BYTES_PER_MB;
+    CgroupInfo {
+        path: path.to_string(),
+        name: path.rsplit('/').next().filter(|n| !n.is_empty()).unwrap_or("/").to_string(),
+        container: detect_container(path),
+        owner_uid: user_service_uid(path),
+        // The root cgroup has no memory.current/pids.current; its usage is the whole system
+        memory_current_mb: read_trimmed(&dir.join("memory.current")).and_then(|v| v.parse().ok()).map(bytes_to_mb),
+        memory_max_mb: read_trimmed(&dir.join("memory.max")).and_then(|v| v.parse().ok()).map(bytes_to_mb), // "max" = unlimited
+        pids: read_trimmed(&dir.join("pids.current")).and_then(|v| v.parse().ok()),
+        process_count: read_trimmed(&dir.join("cgroup.procs")).map(|procs| procs.lines().count()).unwrap_or(0),
+        ..CgroupInfo::default()
+    }
+}
+
+/// Every process in the cgroup at `path` (relative to the unified root) and the groups below it.
+pub fn subtree_pids(path: &str) -> Vec<u32> {
+    let Some(root) = unified_root(Path::new(CGROUP_ROOT)) else { return Vec::new() };
+    let dir = root.join(path.trim_start_matches('/'));
+    let mut pids: Vec<u32> = walk_cgroups(&dir)
+        .into_iter()
+        .filter_map(|(_, dir)| read_trimmed(&dir.join("cgroup.procs")))
+        .flat_map(|procs| procs.lines().filter_map(|pid| pid.parse().ok()).collect::<Vec<u32>>())
+        .collect();
+    pids.sort_unstable();
+    pids
+}
+
+fn is_within(path: &str, ancestor: &str) -> bool {
+    path.strip_prefix(ancestor).is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
+
+// --- systemd units ---
+
+// systemd names each unit's cgroup after the unit; slices only group other units
+fn unit_type(name: &str) -> Option<&'static str> {
+    if name.starts_with("session-") && name.ends_with(".scope") {
+        Some("session")
+    } else if name.ends_with(".service") {
+        Some("service")
+    } else if name.ends_with(".scope") {
+        Some("scope")
+    } else {
+        None
+    }
+}
+
+// The unit's own state isn't in cgroupfs, but cgroup.events tells whether anything still runs in it
+fn unit_state(dir: &Path) -> &'static str {
+    let events = fs::read_to_string(dir.join("cgroup.events")).unwrap_or_default();
+    let flag = |key: &str| events.lines().any(|line| line.strip_prefix(key).is_some_and(|v| v.trim() == "1"));
+    if flag("frozen ") {
+        "frozen"
+    } else if flag("populated ") {
+        "active"
+    } else {
+        "inactive"
+    }
+}
+
+fn systemd_unit(info: &CgroupInfo, unit_type: &'static str, dir: &Path) -> SystemdUnit {
+    SystemdUnit {
+        name: info.name.clone(),
+        unit_type: unit_type.to_string(),
+        cgroup_path: info.path.clone(),
+        slice: info.path.rsplit('/').skip(1).find(|c| c.ends_with(".slice")).map(str::to_string),
+        // Sessions sit in user-UID.slice rather than below user@UID.service
+        owner_uid: info.owner_uid.or_else(|| {
+            info.path.split('/').find_map(|c| c.strip_prefix("user-")?.strip_suffix(".slice")?.parse().ok())
+        }),
+        active_state: unit_state(dir).to_string(),
+        cpu_usage: info.cpu_usage,
+        memory_mb: info.memory_current_mb,
+        io_read_kbps: info.io_read_kbps,
+        io_write_kbps: info.io_write_kbps,
+        tasks: info.pids,
+        process_count: 0, // Summed over the subtree once every cgroup is read
+    }
+}
+
+// --- Containers ---
+
+fn is_container_id(id: &str) -> bool {
+    id.len() == 64 && id.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+/// Recognises containers from their cgroup path:
+/// - docker:     `/system.slice/docker-<id>.scope` (systemd driver) or `/docker/<id>` (cgroupfs driver)
+/// - podman:     `.../libpod-<id>.scope` or `/machine.slice/libpod-<id>.scope/container` (also rootless, under user@UID.service)
+/// - kubernetes: `/kubepods.slice/.../kubepods-...-pod<uid>.slice/cri-containerd-<id>.scope`
+///   (or `crio-`/`docker-`), or `/kubepods/<qos>/pod<uid>/<id>` with the cgroupfs driver
+///
+/// Every cgroup below a container's own (e.g. podman's `container` leaf) belongs to it too.
+pub fn detect_container(path: &str) -> Option<ContainerRef> {
+    let components: Vec<&str> = path.split('/').filter(|c| !c.is_empty()).collect();
+    let in_kubepods = components.first().is_some_and(|c| c.starts_with("kubepods"));
+    // "kubepods-burstable-pod<uid>.slice" (systemd driver) or "pod<uid>" (cgroupfs driver)
+    let pod_uid = components
+        .iter()
+        .filter(|_| in_kubepods)
+        .find_map(|c| {
+            let name = c.strip_suffix(".slice").unwrap_or(c);
+            name.rsplit_once("-pod").map(|(_, uid)| uid).or_else(|| name.strip_prefix("pod"))
+        })
+        .map(|uid| uid.replace('_', "-")); // systemd escapes the UID's dashes as underscores
+
+    for (index, component) in components.iter().enumerate() {
+        let scope = component.strip_suffix(".scope").unwrap_or(component);
+        let prefixed = [("docker-", "docker"), ("libpod-", "podman"), ("cri-containerd-", "containerd"), ("crio-", "cri-o")]
+            .into_iter()
+            .find_map(|(prefix, runtime)| Some((runtime, scope.strip_prefix(prefix)?)))
+            .filter(|(_, id)| is_container_id(id));
+        // cgroupfs drivers use the bare id, under /docker/ or a kubepods pod directory
+        let bare = (is_container_id(component) && index > 0)
+            .then(|| match components[index - 1] {
+                "docker" => Some(("docker", *component)),
+                parent if in_kubepods && parent.starts_with("pod") => Some(("kubernetes", *component)),
+                _ => None,
+            })
+            .flatten();
+        if let Some((runtime, id)) = prefixed.or(bare) {
+            return Some(ContainerRef {
+                runtime: if in_kubepods { "kubernetes" } else { runtime }.to_string(),
+                id: id.to_string(),
+                pod_uid,
+            });
+        }
+    }
+    None
+}
+
+// Rootless containers and user services live under /user.slice/user-UID.slice/user@UID.service
+fn user_service_uid(path: &str) -> Option<u32> {
+    path.split('/').find_map(|c| c.strip_prefix("user@")?.strip_suffix(".service")?.parse().ok())
+}
+
+// --- Per-process membership ---
+
+/// The cgroup v2 path of a process ("0::/path" in /proc/<pid>/cgroup), relative to the unified root.
+#[cfg(target_os = "linux")]
+pub fn process_cgroup(pid: u32) -> Option<String> {
+    fs::read_to_string(format!("/proc/{}/cgroup", pid))
+        .ok()?
+        .lines()
+        .find_map(|line| line.strip_prefix("0::"))
+        .map(|path| path.trim_end_matches(" (deleted)").to_string())
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn process_cgroup(_pid: u32) -> Option<String> {
+    None
+}
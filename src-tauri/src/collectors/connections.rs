@@ -0,0 +1,152 @@
+// TauriViteReact/src-tauri/src/collectors/connections.rs
+// TCP/UDP sockets (IPv4 + IPv6) from /proc/net, resolved to their owning process by
+// matching socket inodes against /proc/<pid>/fd. The table (with the costly fd scan)
+// is only built on demand, never as part of the collection cycle.
+
+use serde::Serialize;
+use std::collections::HashMap;
+#[cfg(target_os = "linux")]
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+#[derive(Serialize, Clone, Debug)]
+pub struct NetworkConnection {
+    pub protocol: &'static str, // "tcp", "tcp6", "udp", "udp6"
+    pub local_address: String,
+    pub local_port: u16,
+    pub remote_address: String,
+    pub remote_port: u16,
+    pub state: &'static str,    // TCP state name; UDP is "UNCONN" or "ESTABLISHED"
+    pub tx_queue: u64,          // Bytes
+    pub rx_queue: u64,          // Bytes
+    pub uid: u32,
+    pub inode: u64,
+    pub pid: Option<u32>,       // None if the socket is owned by another user's process (no access to its fds)
+    pub process_name: Option<String>,
+}
+
+/// Every socket with its owning process, sorted by protocol and local port.
+pub fn list_connections() -> Vec<NetworkConnection> {
+    let mut sockets = read_sockets();
+    let owners = socket_owners();
+    for socket in &mut sockets {
+        if let Some((pid, name)) = owners.get(&socket.inode) {
+            socket.pid = Some(*pid);
+            socket.process_name = Some(name.clone());
+        }
+    }
+    sockets.sort_by(|a, b| a.protocol.cmp(b.protocol).then(a.local_port.cmp(&b.local_port)).then(a.inode.cmp(&b.inode)));
+    sockets
+}
+
+// --- /proc/net parsing ---
+
+#[cfg(target_os = "linux")]
+const TCP_STATES: [&str; 13] = [
+    "UNKNOWN", "ESTABLISHED", "SYN_SENT", "SYN_RECV", "FIN_WAIT1", "FIN_WAIT2", "TIME_WAIT",
+    "CLOSE", "CLOSE_WAIT", "LAST_ACK", "LISTEN", "CLOSING", "NEW_SYN_RECV",
+];
+
+#[cfg(target_os = "linux")]
+fn read_sockets() -> Vec<NetworkConnection> {
+    let mut sockets = Vec::new();
+    for protocol in ["tcp", "tcp6", "udp", "udp6"] {
+        let Ok(content) = std::fs::read_to_string(format!("/proc/net/{}", protocol)) else { continue };
+        // First line is the column header
+        sockets.extend(content.lines().skip(1).filter_map(|line| parse_socket_line(protocol, line)));
+    }
+    sockets
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_sockets() -> Vec<NetworkConnection> {
+    Vec::new()
+}
+
+// "  0: 0100007F:1F90 00000000:0000 0A 00000000:00000000 00:00000000 00000000  1000 0 12345 ..."
+#[cfg(target_os = "linux")]
+fn parse_socket_line(protocol: &'static str, line: &str) -> Option<NetworkConnection> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    let (local_address, local_port) = parse_endpoint(fields.get(1)?)?;
+    let (remote_address, remote_port) = parse_endpoint(fields.get(2)?)?;
+    let state_code = u8::from_str_radix(fields.get(3)?, 16).ok()?;
+    let state = if protocol.starts_with("udp") {
+        // UDP reuses the TCP codes: 1 for connected sockets, 7 (CLOSE) for everything else
+        if state_code == 1 { "ESTABLISHED" } else { "UNCONN" }
+    } else {
+        TCP_STATES.get(state_code as usize).copied().unwrap_or("UNKNOWN")
+    };
+    let (tx_queue, rx_queue) = fields.get(4)?.split_once(':')?;
+    Some(NetworkConnection {
+        protocol,
+        local_address: local_address.to_string(),
+        local_port,
+        remote_address: remote_address.to_string(),
+        remote_port,
+        state,
+        tx_queue: u64::from_str_radix(tx_queue, 16).ok()?,
+        rx_queue: u64::from_str_radix(rx_queue, 16).ok()?,
+        uid: fields.get(7)?.parse().ok()?,
+        inode: fields.get(9)?.parse().ok()?,
+        pid: None,
+        process_name: None,
+    })
+}
+
+// Addresses are hex dumps of the in-kernel (network order) bytes, read as native-endian
+// 32-bit words; ports are plain hex.
+#[cfg(target_os = "linux")]
+fn parse_endpoint(field: &str) -> Option<(IpAddr, u16)> {
+    let (address, port) = field.split_once(':')?;
+    let port = u16::from_str_radix(port, 16).ok()?;
+    let words: Vec<u32> = (0..address.len() / 8)
+        .map(|i| u32::from_str_radix(&address[i * 8..i * 8 + 8], 16).map(u32::to_ne_bytes))
+        .map(|bytes| bytes.map(u32::from_be_bytes))
+        .collect::<std::result::Result<_, _>>()
+        .ok()?;
+    let address = match words.as_slice() {
+        [a] => IpAddr::V4(Ipv4Addr::from(*a)),
+        [a, b, c, d] => {
+            let v6 = Ipv6Addr::from((*a as u128) << 96 | (*b as u128) << 64 | (*c as u128) << 32 | *d as u128);
+            // Show IPv4-mapped addresses (dual-stack sockets) in their IPv4 form
+            v6.to_ipv4_mapped().map(IpAddr::V4).unwrap_or(IpAddr::V6(v6))
+        }
+        _ => return None,
+    };
+    Some((address, port))
+}
+
+// --- Socket inode -> owning process ---
+
+#[cfg(target_os = "linux")]
+fn socket_owners() -> HashMap<u64, (u32, String)> {
+    let mut owners = HashMap::new();
+    let Ok(proc_entries) = std::fs::read_dir("/proc") else { return owners };
+    for entry in proc_entries.flatten() {
+        let Some(pid) = entry.file_name().to_str().and_then(|name| name.parse::<u32>().ok()) else { continue };
+        // Fails with EACCES for other users' processes unless running as root
+        let Ok(fds) = std::fs::read_dir(entry.path().join("fd")) else { continue };
+        let mut name: Option<String> = None;
+        for fd in fds.flatten() {
+            let Ok(target) = std::fs::read_link(fd.path()) else { continue };
+            let Some(inode) = target
+                .to_str()
+                .and_then(|t| t.strip_prefix("socket:["))
+                .and_then(|t| t.strip_suffix(']'))
+                .and_then(|t| t.parse::<u64>().ok())
+            else {
+                continue;
+            };
+            let name = name.get_or_insert_with(|| {
+                std::fs::read_to_string(entry.path().join("comm")).map(|c| c.trim().to_string()).unwrap_or_default()
+            });
+            // Shared sockets (forked servers) keep the first, lowest-numbered owner found
+            owners.entry(inode).or_insert_with(|| (pid, name.clone()));
+        }
+    }
+    owners
+}
+
+#[cfg(not(target_os = "linux"))]
+fn socket_owners() -> HashMap<u64, (u32, String)> {
+    HashMap::new()
+}
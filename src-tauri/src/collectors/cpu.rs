@@ -1,8 +1,230 @@
 // TauriViteReact/src-tauri/src/collectors/cpu.rs (Modified collect_process_info)
 
-use crate::models::HISTORY_LENGTH;
+use crate::collectors::registry::{CollectContext, Collector, RefreshRequirements};
+use crate::models::{CpuCoreFrequency, CpuInfo, CpuTimeBreakdown, CpuTimes, SystemData, HISTORY_LENGTH};
 use crate::utils::error::{MonitorError, Result}; // Use explicit path
-use sysinfo::{CpuExt, Pid, ProcessExt, System, SystemExt};
+use sysinfo::{CpuExt, Pid, PidExt, ProcessExt, System, SystemExt};
+
+/// Per-core CPU usage and its history, plus model, frequency scaling, load and uptime
+#[derive(Default)]
+pub struct CpuCollector {
+    history: Vec<Vec<f64>>,
+    history_timestamps: Vec<i64>,
+    frequency_history: Vec<Vec<f64>>,
+    frequency_history_timestamps: Vec<i64>,
+    physical_cores: Option<Option<usize>>, // Looked up once; sysinfo re-parses /proc/cpuinfo on every call
+    last_stat: Option<ProcStat>,           // Previous /proc/stat counters for the time breakdown
+    times_history: Vec<CpuTimes>,
+    times_history_timestamps: Vec<i64>,
+}
+
+impl Collector for CpuCollector {
+    fn name(&self) -> &'static str { "cpu" }
+
+    fn refresh_requirements(&self) -> RefreshRequirements {
+        RefreshRequirements { cpu: true, ..RefreshRequirements::NONE }
+    }
+
+    fn collect(&mut self, ctx: &CollectContext<'_>, data: &mut SystemData) -> Result<()> {
+        let now_ms = ctx.timestamp.timestamp_millis();
+        let usage = collect_cpu_usage(ctx.sys)?;
+        if self.history.len() != usage.len() {
+            self.history_timestamps.clear(); // update_cpu_history starts over with zero padding
+        }
+        update_cpu_history(&usage, &mut self.history)?;
+        super::push_history(&mut self.history_timestamps, now_ms);
+        data.cpu_usage = usage;
+        data.cpu_history = self.history.clone();
+        data.cpu_history_timestamps = self.history_timestamps.clone();
+        data.cpu_info = self.collect_cpu_info(ctx.sys, now_ms);
+        data.cpu_times = self.collect_cpu_times(now_ms);
+        Ok(())
+    }
+
+    fn reset_state(&mut self) {
+        self.history.clear();
+        self.history_timestamps.clear();
+        self.frequency_history.clear();
+        self.frequency_history_timestamps.clear();
+        self.last_stat = None;
+        self.times_history.clear();
+        self.times_history_timestamps.clear();
+    }
+}
+
+impl CpuCollector {
+    fn collect_cpu_info(&mut self, system: &System, now_ms: i64) -> CpuInfo {
+        let cpus = system.cpus();
+        let mut frequencies: Vec<CpuCoreFrequency> = cpus
+            .iter()
+            .enumerate()
+            .map(|(core, cpu)| read_core_frequency(core).unwrap_or_else(|| CpuCoreFrequency {
+                current_mhz: cpu.frequency() as f64,
+                ..CpuCoreFrequency::default()
+            }))
+            .collect();
+
+        if self.frequency_history.len() != frequencies.len() {
+            self.frequency_history = vec![Vec::new(); frequencies.len()];
+            self.frequency_history_timestamps.clear();
+        }
+        for (frequency, history) in frequencies.iter_mut().zip(self.frequency_history.iter_mut()) {
+            super::push_history(history, frequency.current_mhz);
+            frequency.history = history.clone();
+        }
+        super::push_history(&mut self.frequency_history_timestamps, now_ms);
+
+        let load = system.load_average();
+        let first = cpus.first();
+        CpuInfo {
+            model: first.map(|cpu| cpu.brand().trim().to_string()).unwrap_or_default(),
+            vendor: first.map(|cpu| cpu.vendor_id().to_string()).unwrap_or_default(),
+            physical_cores: *self.physical_cores.get_or_insert_with(|| system.physical_core_count()),
+            logical_cores: cpus.len(),
+            frequencies,
+            frequency_history_timestamps: self.frequency_history_timestamps.clone(),
+            load_average: [load.one, load.five, load.fifteen],
+            uptime_secs: system.uptime(),
+            boot_time: system.boot_time(),
+        }
+    }
+}
+
+// --- CPU time breakdown (/proc/stat) ---
+
+// Jiffy counters in /proc/stat column order: user nice system idle iowait irq softirq steal guest guest_nice
+type StatCounters = [u64; 10];
+
+#[derive(Clone, Debug)]
+struct ProcStat {
+    total: StatCounters,
+    per_core: Vec<(usize, StatCounters)>,
+}
+
+impl CpuCollector {
+    // The first cycle only primes the counters and reports None
+    fn collect_cpu_times(&mut self, now_ms: i64) -> Option<CpuTimeBreakdown> {
+        let current = read_proc_stat()?;
+        let previous = self.last_stat.replace(current.clone())?;
+
+        let total = times_between(&previous.total, &current.total);
+        let per_core = current
+            .per_core
+            .iter()
+            .map(|(core, counters)| match previous.per_core.iter().find(|(c, _)| c == core) {
+                Some((_, before)) => times_between(before, counters),
+                None => CpuTimes::default(), // Core just came online
+            })
+            .collect();
+
+        super::push_history(&mut self.times_history, total);
+        super::push_history(&mut self.times_history_timestamps, now_ms);
+        Some(CpuTimeBreakdown {
+            total,
+            per_core,
+            history: self.times_history.clone(),
+            history_timestamps: self.times_history_timestamps.clone(),
+        })
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn read_proc_stat() -> Option<ProcStat> {
+    parse_proc_stat(&std::fs::read_to_string("/proc/stat").ok()?)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_proc_stat() -> Option<ProcStat> {
+    None
+}
+
+#[cfg(target_os = "linux")]
+fn parse_proc_stat(content: &str) -> Option<ProcStat> {
+    let mut total = None;
+    let mut per_core = Vec::new();
+    for line in content.lines().take_while(|line| line.starts_with("cpu")) {
+        let mut fields = line.split_whitespace();
+        let label = fields.next()?;
+        // Older kernels have fewer columns; missing ones stay 0
+        let mut counters = StatCounters::default();
+        for (slot, value) in counters.iter_mut().zip(fields) {
+            *slot = value.parse().ok()?;
+        }
+        match label.strip_prefix("cpu").filter(|n| !n.is_empty()) {
+            Some(core) => per_core.push((core.parse().ok()?, counters)),
+            None => total = Some(counters),
+        }
+    }
+    Some(ProcStat { total: total?, per_core })
+}
+
+fn times_between(before: &StatCounters, after: &StatCounters) -> CpuTimes {
+    // Counters can step back briefly when a core goes offline; treat that as no time spent
+    let delta: Vec<f64> = after.iter().zip(before).map(|(a, b)| a.saturating_sub(*b) as f64).collect();
+    let (guest, guest_nice) = (delta[8], delta[9]);
+    // user/nice already include guest/guest_nice, so the first eight columns are the whole interval
+    let elapsed: f64 = delta[..8].iter().sum();
+    if elapsed <= 0.0 {
+        return CpuTimes::default();
+    }
+    let percent = |jiffies: f64| jiffies / elapsed * 100.0;
+    CpuTimes {
+        user: percent((delta[0] - guest).max(0.0)),
+        nice: percent((delta[1] - guest_nice).max(0.0)),
+        system: percent(delta[2]),
+        idle: percent(delta[3]),
+        iowait: percent(delta[4]),
+        irq: percent(delta[5]),
+        softirq: percent(delta[6]),
+        steal: percent(delta[7]),
+        guest: percent(guest + guest_nice),
+    }
+}
+
+// cpufreq exposes kHz; cores without a cpufreq policy (VMs, offline cores) read as None
+#[cfg(target_os = "linux")]
+fn read_core_frequency(core: usize) -> Option<CpuCoreFrequency> {
+    let dir = std::path::PathBuf::from(format!("/sys/devices/system/cpu/cpu{}/cpufreq", core));
+    let read = |file: &str| std::fs::read_to_string(dir.join(file)).ok().map(|s| s.trim().to_string()).filter(|s| !s.is_empty());
+    let mhz = |file: &str| read(file).and_then(|v| v.parse::<f64>().ok()).map(|khz| khz / 1000.0);
+    Some(CpuCoreFrequency {
+        current_mhz: mhz("scaling_cur_freq")?,
+        min_mhz: mhz("scaling_min_freq"),
+        max_mhz: mhz("scaling_max_freq"),
+        hardware_min_mhz: mhz("cpuinfo_min_freq"),
+        hardware_max_mhz: mhz("cpuinfo_max_freq"),
+        governor: read("scaling_governor"),
+        energy_performance_preference: read("energy_performance_preference"),
+        history: Vec::new(),
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_core_frequency(_core: usize) -> Option<CpuCoreFrequency> {
+    None
+}
+
+/// Top processes by CPU usage
+pub struct ProcessCollector;
+
+impl Collector for ProcessCollector {
+    fn name(&self) -> &'static str { "processes" }
+
+    fn refresh_requirements(&self) -> RefreshRequirements {
+        RefreshRequirements { processes: true, ..RefreshRequirements::NONE }
+    }
+
+    fn collect(&mut self, ctx: &CollectContext<'_>, data: &mut SystemData) -> Result<()> {
+        // Convert sysinfo Pids for serialization
+        data.top_processes = collect_process_info(ctx.sys)?
+            .into_iter()
+            .map(|(pid, name, cpu, mem)| (pid.as_u32(), name, cpu, mem))
+            .collect();
+        Ok(())
+    }
+
+    fn reset_state(&mut self) {}
+}
 
 /// Collects current CPU usage percentage for each core
 pub fn collect_cpu_usage(system: &System) -> Result<Vec<f64>> {
@@ -1,15 +1,118 @@
-// TauriViteReact/src-tauri/src/collectors/disk.rs (Enhanced with disk I/O history)
+// TauriViteReact/src-tauri/src/collectors/disk.rs (Real per-disk I/O from /proc/diskstats)
 
-use crate::models::{DiskData, HISTORY_LENGTH};
+use crate::collectors::registry::{CollectContext, Collector, RefreshRequirements};
+use crate::models::{DiskData, SystemData, HISTORY_LENGTH};
 use crate::utils::error::Result;
 use std::collections::HashMap;
 use std::time::Instant;
-use sysinfo::{DiskExt, DiskKind, System, SystemExt};
+use sysinfo::{DiskExt, DiskKind, ProcessExt, System, SystemExt};
+
+/// Per-disk space usage and I/O rates
+#[derive(Default)]
+pub struct DiskCollector {
+    state: HashMap<String, DiskData>,
+}
+
+impl Collector for DiskCollector {
+    fn name(&self) -> &'static str { "disk" }
+
+    fn refresh_requirements(&self) -> RefreshRequirements {
+        RefreshRequirements { disks: true, ..RefreshRequirements::NONE }
+    }
+
+    fn collect(&mut self, ctx: &CollectContext<'_>, data: &mut SystemData) -> Result<()> {
+        collect_disk_data(ctx.sys, &mut self.state, ctx.now, ctx.timestamp.timestamp_millis())?;
+        data.disk_data = self.state.clone();
+        Ok(())
+    }
+
+    fn reset_state(&mut self) { self.state.clear(); }
+}
+
+/// System-wide disk read/write rates, summed from per-process disk usage
+#[derive(Default)]
+pub struct SystemDiskIoCollector {
+    read_history: Vec<f64>,
+    write_history: Vec<f64>,
+    history_timestamps: Vec<i64>,
+    last_update_instant: Option<Instant>, // To calculate delta time for rates
+}
+
+impl Collector for SystemDiskIoCollector {
+    fn name(&self) -> &'static str { "system_disk_io" }
+
+    fn refresh_requirements(&self) -> RefreshRequirements {
+        RefreshRequirements { processes: true, ..RefreshRequirements::NONE }
+    }
+
+    fn collect(&mut self, ctx: &CollectContext<'_>, data: &mut SystemData) -> Result<()> {
+        let (read_kb_per_sec, write_kb_per_sec) = self.update(ctx.sys, ctx.now);
+        super::push_history(&mut self.history_timestamps, ctx.timestamp.timestamp_millis());
+        data.system_disk_read_per_sec = read_kb_per_sec;
+        data.system_disk_write_per_sec = write_kb_per_sec;
+        data.system_disk_read_history = self.read_history.clone();
+        data.system_disk_write_history = self.write_history.clone();
+        data.system_disk_history_timestamps = self.history_timestamps.clone();
+        Ok(())
+    }
+
+    fn reset_state(&mut self) {
+        self.read_history.clear();
+        self.write_history.clear();
+        self.history_timestamps.clear();
+        self.last_update_instant = None;
+    }
+}
+
+impl SystemDiskIoCollector {
+    // Returns (read_kb_per_sec, write_kb_per_sec)
+    fn update(&mut self, system: &System, now_instant: Instant) -> (f64, f64) {
+        let mut total_read_delta: u64 = 0;
+        let mut total_write_delta: u64 = 0;
+
+        // Iterate through processes and sum disk usage deltas
+        for process in system.processes().values() {
+            let usage = process.disk_usage();
+            total_read_delta += usage.read_bytes;
+            total_write_delta += usage.written_bytes;
+        }
+
+        let mut read_kb_per_sec = 0.0;
+        let mut write_kb_per_sec = 0.0;
+
+        if let Some(last_time) = self.last_update_instant {
+            let delta_time = now_instant.duration_since(last_time).as_secs_f64();
+            if delta_time > 0.001 { // Avoid division by zero or tiny intervals
+                read_kb_per_sec = (total_read_delta as f64 / delta_time) / 1024.0;
+                write_kb_per_sec = (total_write_delta as f64 / delta_time) / 1024.0;
+                super::push_history(&mut self.read_history, read_kb_per_sec);
+                super::push_history(&mut self.write_history, write_kb_per_sec);
+            } else {
+                // Delta time too small, reuse last history value if available
+                let last_read = self.read_history.last().copied().unwrap_or(0.0);
+                let last_write = self.write_history.last().copied().unwrap_or(0.0);
+                super::push_history(&mut self.read_history, last_read);
+                super::push_history(&mut self.write_history, last_write);
+            }
+        } else {
+            // First run, push 0 to history
+            if self.read_history.is_empty() { self.read_history.push(0.0); }
+            if self.write_history.is_empty() { self.write_history.push(0.0); }
+        }
+
+        // Update the last update time for the next calculation
+        self.last_update_instant = Some(now_instant);
+
+        (read_kb_per_sec, write_kb_per_sec)
+    }
+}
+
 
 pub fn collect_disk_data(
     system: &System,
     disk_data_map: &mut HashMap<String, DiskData>,
     now: Instant,
+    timestamp_ms: i64,
 ) -> Result<()> {
     // Log the raw disks found by sysinfo *before* processing
     let disks_list = system.disks();
@@ -24,9 +127,8 @@ pub fn collect_disk_data(
 
     let mut current_disk_names = Vec::new(); // Keep track of names we actually process
 
-    // Collect system-wide I/O stats (total of all disks) - Corrected: prefix unused variables
-    let mut _total_read_bytes_per_sec = 0.0;
-    let mut _total_write_bytes_per_sec = 0.0;
+    // Cumulative (read_bytes, write_bytes) per mount point, empty where unsupported
+    let io_counters = read_disk_io_counters();
 
     for disk in disks_list {
         let name = disk.name().to_string_lossy().to_string();
@@ -67,14 +169,6 @@ pub fn collect_disk_data(
         let total_gb = total_bytes as f64 / (1024.0 * 1024.0 * 1024.0);
         let used_gb = used_bytes as f64 / (1024.0 * 1024.0 * 1024.0);
 
-        // Since current sysinfo doesn't expose I/O methods directly on Disk
-        // We'll use a simulated approach for demo purposes - can be replaced with real
-        // disk stats when sysinfo adds direct support for this
-
-        // Using disk_io_counters would be more accurate in a real implementation
-        // For now, let's generate some simulated I/O activity based on disk usage
-        // This is only for demo/testing - replace with actual metrics in production
-
         // Check if this is the first entry in our tracking map
         let entry = disk_data_map.entry(name.clone()).or_insert_with(|| {
             log::debug!("Adding new entry for disk: {}", name);
@@ -87,8 +181,8 @@ pub fn collect_disk_data(
             );
 
             // Initialize with zeros
-            disk_data.read_bytes_per_sec = 0.0;
-            disk_data.write_bytes_per_sec = 0.0;
+            disk_data.read_kb_per_sec = 0.0;
+            disk_data.write_kb_per_sec = 0.0;
 
             disk_data
         });
@@ -100,52 +194,48 @@ pub fn collect_disk_data(
         entry.mount_point = mount_point;
         entry.used_percentage = if total_gb > 0.0 { (used_gb / total_gb) * 100.0 } else { 0.0 };
 
-        // Simulate read/write activity based on disk usage and random fluctuation
-        // In a production app, replace this with actual disk I/O metrics
-        // For now, generate some data for testing the history charts
-        let activity_factor = entry.used_percentage / 100.0 * 10.0; // base activity on disk usage %
-
-        if let Some(last_time) = entry.last_update_time {
-            let delta_time = now.duration_since(last_time).as_secs_f64();
-            if delta_time > 0.001 {
-                // Generate some simulated I/O rates - replace with real metrics when available
-                let read_rate = activity_factor * (1.0 + (now.elapsed().as_millis() as f64 % 50.0) / 10.0);
-                let write_rate = activity_factor * 0.8 * (1.0 + (now.elapsed().as_millis() as f64 % 60.0) / 12.0);
-
-                // Apply smoothing with previous values if available
-                if entry.read_history.is_empty() {
-                    entry.read_bytes_per_sec = read_rate;
-                    entry.write_bytes_per_sec = write_rate;
+        // Real I/O rates from the per-device counters (KB/s, like network speeds)
+        match io_counters.get(&entry.mount_point) {
+            Some(&(read_bytes, write_bytes)) => {
+                if let (Some(last_time), Some(last_read), Some(last_write)) =
+                    (entry.last_update_time, entry.last_read_bytes, entry.last_write_bytes)
+                {
+                    let delta_time = now.duration_since(last_time).as_secs_f64();
+                    if delta_time > 0.001 {
+                        // Counters can go backwards if the device was re-attached; treat that as no activity
+                        let delta_read = read_bytes.saturating_sub(last_read);
+                        let delta_write = write_bytes.saturating_sub(last_write);
+                        entry.read_kb_per_sec = (delta_read as f64 / delta_time) / 1024.0;
+                        entry.write_kb_per_sec = (delta_write as f64 / delta_time) / 1024.0;
+                    }
                 } else {
-                    // Add some smoothing
-                    entry.read_bytes_per_sec = entry.read_bytes_per_sec * 0.7 + read_rate * 0.3;
-                    entry.write_bytes_per_sec = entry.write_bytes_per_sec * 0.7 + write_rate * 0.3;
+                    // First sample for this disk, no delta yet
+                    entry.read_kb_per_sec = 0.0;
+                    entry.write_kb_per_sec = 0.0;
                 }
-
-                // Add to total for system-wide stats - Corrected: prefix unused variables
-                _total_read_bytes_per_sec += entry.read_bytes_per_sec;
-                _total_write_bytes_per_sec += entry.write_bytes_per_sec;
+                entry.last_read_bytes = Some(read_bytes);
+                entry.last_write_bytes = Some(write_bytes);
+            }
+            None => {
+                log::trace!("No I/O counters found for mount point '{}'", entry.mount_point);
+                entry.read_kb_per_sec = 0.0;
+                entry.write_kb_per_sec = 0.0;
+                entry.last_read_bytes = None;
+                entry.last_write_bytes = None;
             }
-        } else {
-            // First time update, initialize with some small value
-            entry.read_bytes_per_sec = activity_factor;
-            entry.write_bytes_per_sec = activity_factor * 0.5;
-
-            // Add to total for system-wide stats - Corrected: prefix unused variables
-            _total_read_bytes_per_sec += entry.read_bytes_per_sec;
-            _total_write_bytes_per_sec += entry.write_bytes_per_sec;
         }
 
         // Update I/O history
         if entry.read_history.len() >= HISTORY_LENGTH {
             entry.read_history.remove(0);
         }
-        entry.read_history.push(entry.read_bytes_per_sec);
+        entry.read_history.push(entry.read_kb_per_sec);
 
         if entry.write_history.len() >= HISTORY_LENGTH {
             entry.write_history.remove(0);
         }
-        entry.write_history.push(entry.write_bytes_per_sec);
+        entry.write_history.push(entry.write_kb_per_sec);
+        super::push_history(&mut entry.history_timestamps, timestamp_ms);
 
         // Update timestamp for next delta calculation
         entry.last_update_time = Some(now);
@@ -156,4 +246,83 @@ pub fn collect_disk_data(
     log::debug!("Disk data map finalized with {} entries.", disk_data_map.len());
 
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Reads cumulative (read_bytes, write_bytes) counters for every mounted block device,
+/// keyed by mount point so they can be matched against sysinfo's disk list.
+#[cfg(target_os = "linux")]
+fn read_disk_io_counters() -> HashMap<String, (u64, u64)> {
+    use std::fs;
+
+    let diskstats = match fs::read_to_string("/proc/diskstats") {
+        Ok(content) => parse_diskstats(&content),
+        Err(e) => {
+            log::warn!("Failed to read /proc/diskstats: {}", e);
+            return HashMap::new();
+        }
+    };
+    let mounts = diskspace::unix::read_mounts();
+    if mounts.is_empty() {
+        log::warn!("No mounts found in /proc/self/mountinfo");
+        return HashMap::new();
+    }
+
+    // Secondary lookup by kernel device name, for filesystems (e.g. btrfs) whose
+    // mountinfo major:minor is an anonymous device rather than the backing disk.
+    let by_name: HashMap<&str, (u64, u64)> = diskstats
+        .values()
+        .map(|(name, read, write)| (name.as_str(), (*read, *write)))
+        .collect();
+
+    let mut counters = HashMap::new();
+    for mount in mounts {
+        let stats = diskstats.get(&mount.device).map(|(_, read, write)| (*read, *write)).or_else(|| {
+            if !mount.source.starts_with("/dev/") {
+                return None;
+            }
+            // Resolve /dev/mapper/* and /dev/disk/by-* symlinks to the kernel name (e.g. dm-0)
+            let resolved = fs::canonicalize(&mount.source).unwrap_or_else(|_| mount.source.clone().into());
+            let kernel_name = resolved.file_name()?.to_string_lossy().to_string();
+            by_name.get(kernel_name.as_str()).copied()
+        });
+
+        if let Some(stats) = stats {
+            counters.insert(mount.mount_point, stats);
+        }
+    }
+    log::trace!("Resolved disk I/O counters for {} mount points", counters.len());
+    counters
+}
+
+/// Per-disk I/O counters are only implemented for Linux so far.
+#[cfg(not(target_os = "linux"))]
+fn read_disk_io_counters() -> HashMap<String, (u64, u64)> {
+    HashMap::new()
+}
+
+/// Parses /proc/diskstats into "major:minor" -> (device name, read bytes, written bytes).
+#[cfg(target_os = "linux")]
+fn parse_diskstats(content: &str) -> HashMap<String, (String, u64, u64)> {
+    // The kernel always reports sectors in 512-byte units here, regardless of the device's sector size
+    const SECTOR_SIZE: u64 = 512;
+
+    content
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 10 {
+                return None;
+            }
+            let sectors_read = fields[5].parse::<u64>().ok()?;
+            let sectors_written = fields[9].parse::<u64>().ok()?;
+            Some((
+                format!("{}:{}", fields[0], fields[1]),
+                (
+                    fields[2].to_string(),
+                    sectors_read.saturating_mul(SECTOR_SIZE),
+                    sectors_written.saturating_mul(SECTOR_SIZE),
+                ),
+            ))
+        })
+        .collect()
+}
@@ -1,6 +1,7 @@
 // TauriViteReact/src/collectors/gpu.rs (Show Both GPUs)
 
-use crate::models::{GpuData, HISTORY_LENGTH};
+use crate::collectors::registry::{CollectContext, Collector};
+use crate::models::{GpuData, SystemData, HISTORY_LENGTH};
 // Corrected: Removed unused MonitorError import
 use crate::utils::error::Result;
 use std::collections::{HashMap, HashSet}; // Import HashSet
@@ -20,6 +21,38 @@ use crate::collectors::windows_gpu_helpers::collect_windows_gpu_data;
 #[cfg(feature = "nvml-support")] // Ensure feature name matches Cargo.toml
 use nvml_wrapper::Nvml;
 
+/// GPU metrics keyed by GPU name, with per-GPU utilization history.
+/// Reads `data.cpu_usage` for the fallback simulation, so it runs after the cpu collector.
+#[derive(Default)]
+pub struct GpuCollector {
+    utilization_history: HashMap<String, Vec<f64>>,
+    history_timestamps: HashMap<String, Vec<i64>>, // Same keys; the helpers push one sample per GPU per cycle
+}
+
+impl Collector for GpuCollector {
+    fn name(&self) -> &'static str { "gpu" }
+
+    fn collect(&mut self, ctx: &CollectContext<'_>, data: &mut SystemData) -> Result<()> {
+        let mut gpus = collect_gpu_data_entry(ctx.sys, &data.cpu_usage, &mut self.utilization_history)?;
+        for gpu in &mut gpus {
+            let timestamps = self.history_timestamps.entry(gpu.name.clone()).or_default();
+            super::push_history(timestamps, ctx.timestamp.timestamp_millis());
+            // Only stamps for samples still in the history (it restarts if the helper lost it)
+            let excess = timestamps.len().saturating_sub(gpu.utilization_history.len());
+            timestamps.drain(..excess);
+            gpu.history_timestamps = timestamps.clone();
+        }
+        self.history_timestamps.retain(|name, _| gpus.iter().any(|gpu| &gpu.name == name));
+        data.gpu_data = gpus;
+        Ok(())
+    }
+
+    fn reset_state(&mut self) {
+        self.utilization_history.clear();
+        self.history_timestamps.clear();
+    }
+}
+
 /// Public entry point for collecting GPU data.
 pub fn collect_gpu_data_entry(
     system: &System,
@@ -181,6 +214,7 @@ fn collect_fallback_gpu_data(
         memory_total: 4.0, // Assume 4GB total for simulation
         power_usage: (5.0 + (gpu_utilization * 0.15)).clamp(3.0, 25.0), // Estimate power
         utilization_history: history.clone(), // Clone the updated history
+        history_timestamps: Vec::new(), // Stamped by GpuCollector
     });
     log::debug!("Added simulated GPU data.");
     Ok(())
@@ -82,6 +82,7 @@ pub fn collect_nvidia_gpu_data(
             memory_total: memory_total_gb.max(0.0), // Ensure non-negative
             power_usage: power_usage.max(0.0), // Ensure non-negative
             utilization_history: history.clone(),
+            history_timestamps: Vec::new(), // Stamped by GpuCollector
         });
     }
     Ok(())
@@ -154,6 +155,7 @@ fn parse_lspci_output(
                     memory_total: 0.0, 
                     power_usage: 0.0, 
                     utilization_history: vec![], 
+                    history_timestamps: Vec::new(), // Stamped by GpuCollector
                 });
             } else { 
                 current_gpu_info = None; 
@@ -224,6 +226,7 @@ fn collect_sysfs_gpu_data(
                         memory_total: 0.0, 
                         power_usage: 0.0, 
                         utilization_history: vec![], 
+                        history_timestamps: Vec::new(), // Stamped by GpuCollector
                     };
                     let sysfs_base_path_str = device_path.to_string_lossy();
                     if is_amd { collect_amd_gpu_sysfs_data_for_path(&mut gpu_info, &sysfs_base_path_str); }
@@ -365,7 +368,8 @@ fn collect_radeontop_data(
                                 memory_used: 0.0, 
                                 memory_total: 4.0, 
                                 power_usage: 0.0, 
-                                utilization_history: vec![] 
+                                utilization_history: vec![],
+                                history_timestamps: Vec::new(), // Stamped by GpuCollector
                             }; 
                             if let Some(gpu_idx) = output_str.find("gpu") { 
                                 let potential_num = output_str[gpu_idx..].chars().skip(3).take_while(|&c| c.is_ascii_digit() || c == '.').collect::<String>(); 
@@ -406,7 +410,8 @@ fn collect_intel_gpu_top_data(
             memory_used: 0.0, 
             memory_total: 0.0, 
             power_usage: 0.0, 
-            utilization_history: vec![] 
+            utilization_history: vec![],
+            history_timestamps: Vec::new(), // Stamped by GpuCollector
         };
         if collect_intel_gpu_top_data_single(&mut gpu_info).is_ok() {
             let history = gpu_history.entry(gpu_info.name.clone()).or_insert_with(|| Vec::with_capacity(HISTORY_LENGTH)); 
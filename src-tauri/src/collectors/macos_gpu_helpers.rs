@@ -70,6 +70,7 @@ pub fn collect_macos_gpu_data(
                                     memory_total: memory_total_gb,
                                     power_usage,
                                     utilization_history: history.clone(),
+                                    history_timestamps: Vec::new(), // Stamped by GpuCollector
                                 });
                                 found_gpus.insert(name);
                             }
@@ -109,6 +110,7 @@ pub fn collect_macos_gpu_data(
             name, utilization, temperature,
             memory_used: memory_used_gb, memory_total: memory_total_gb,
             power_usage, utilization_history: history.clone(),
+            history_timestamps: Vec::new(), // Stamped by GpuCollector
         });
     }
 
@@ -1,7 +1,51 @@
-use crate::models::HISTORY_LENGTH; // Corrected: Import from models
+use crate::collectors::registry::{CollectContext, Collector, RefreshRequirements};
+use crate::models::{MemoryBreakdown, MemoryMetric, SystemData, HISTORY_LENGTH}; // Corrected: Import from models
 use crate::utils::error::{MonitorError, Result};// Corrected: Use Result from utils
+use std::collections::HashMap;
 use sysinfo::{System, SystemExt};
 
+const KB_PER_GB: f64 = 1024.0 * 1024.0;
+
+/// Used/total memory and the usage-percent history, plus the /proc/meminfo breakdown
+#[derive(Default)]
+pub struct MemoryCollector {
+    history: Vec<f64>,
+    history_timestamps: Vec<i64>,
+    details: Option<MemoryBreakdown>, // Carries the per-field histories between cycles
+}
+
+impl Collector for MemoryCollector {
+    fn name(&self) -> &'static str { "memory" }
+
+    fn refresh_requirements(&self) -> RefreshRequirements {
+        RefreshRequirements { memory: true, ..RefreshRequirements::NONE }
+    }
+
+    fn collect(&mut self, ctx: &CollectContext<'_>, data: &mut SystemData) -> Result<()> {
+        let (used_gb, total_gb, used_percent) = collect_memory_info(ctx.sys)?;
+        let now_ms = ctx.timestamp.timestamp_millis();
+        update_memory_history(used_percent, &mut self.history)?;
+        super::push_history(&mut self.history_timestamps, now_ms);
+        data.memory_used = used_gb;
+        data.memory_total = total_gb;
+        data.memory_history = self.history.clone();
+        data.memory_history_timestamps = self.history_timestamps.clone();
+        data.memory_details = read_meminfo().map(|meminfo| {
+            let details = self.details.get_or_insert_with(MemoryBreakdown::default);
+            update_memory_breakdown(details, &meminfo);
+            super::push_history(&mut details.history_timestamps, now_ms);
+            details.clone()
+        });
+        Ok(())
+    }
+
+    fn reset_state(&mut self) {
+        self.history.clear();
+        self.history_timestamps.clear();
+        self.details = None;
+    }
+}
+
 /// Collects current memory usage (Used GB, Total GB, Used %)
 pub fn collect_memory_info(system: &System) -> Result<(f64, f64, f64)> { // Made pub
     let total_gb = system.total_memory() as f64 / (1024.0 * 1024.0 * 1024.0);
@@ -24,4 +68,55 @@ pub fn update_memory_history(current_percent: f64, history: &mut Vec<f64>) -> Re
     }
     history.push(current_percent.clamp(0.0, 100.0)); // Ensure value is clamped
     Ok(())
-}
\ No newline at end of file
+}
+// --- /proc/meminfo ---
+
+#[cfg(target_os = "linux")]
+fn read_meminfo() -> Option<HashMap<String, u64>> {
+    std::fs::read_to_string("/proc/meminfo").ok().map(|content| parse_meminfo(&content))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_meminfo() -> Option<HashMap<String, u64>> {
+    None
+}
+
+/// Parses "Key:   value kB" lines. Values stay in kB; HugePages_* lines are page counts.
+pub fn parse_meminfo(content: &str) -> HashMap<String, u64> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let (key, rest) = line.split_once(':')?;
+            let value = rest.split_whitespace().next()?.parse().ok()?;
+            Some((key.trim().to_string(), value))
+        })
+        .collect()
+}
+
+// Fields missing on older kernels (e.g. MemAvailable before 3.14) read as 0
+fn update_memory_breakdown(details: &mut MemoryBreakdown, meminfo: &HashMap<String, u64>) {
+    let kb = |key: &str| meminfo.get(key).copied().unwrap_or(0);
+    let record = |metric: &mut MemoryMetric, value_kb: u64| {
+        metric.value = value_kb as f64 / KB_PER_GB;
+        super::push_history(&mut metric.history, metric.value);
+    };
+
+    details.total = kb("MemTotal") as f64 / KB_PER_GB;
+    record(&mut details.available, kb("MemAvailable"));
+    record(&mut details.free, kb("MemFree"));
+    record(&mut details.anonymous, kb("AnonPages"));
+    record(&mut details.buffers, kb("Buffers"));
+    record(&mut details.cached, kb("Cached"));
+    record(&mut details.shared, kb("Shmem"));
+    record(&mut details.slab_reclaimable, kb("SReclaimable"));
+    record(&mut details.slab_unreclaimable, kb("SUnreclaim"));
+    record(&mut details.dirty, kb("Dirty"));
+    record(&mut details.writeback, kb("Writeback"));
+    record(&mut details.committed, kb("Committed_AS"));
+    details.commit_limit = kb("CommitLimit") as f64 / KB_PER_GB;
+
+    details.hugepages_total = kb("HugePages_Total");
+    details.hugepages_free = kb("HugePages_Free");
+    details.hugepage_size_kb = kb("Hugepagesize");
+    record(&mut details.hugepages_used, details.hugepages_total.saturating_sub(details.hugepages_free) * details.hugepage_size_kb);
+}
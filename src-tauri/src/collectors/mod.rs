@@ -1,12 +1,21 @@
 // TauriViteReact/src-tauri/src/collectors/mod.rs (Corrected v3)
 
 // Make necessary modules public
+pub mod cgroups;
+pub mod connections;
 pub mod cpu;
 pub mod disk;
 pub mod gpu;
 pub mod memory;
 pub mod network;
-// pub mod processes; // <-- Ensure this line is REMOVED or commented out
+pub mod power;
+pub mod pressure;
+pub mod processes;
+pub mod rapl;
+pub mod registry;
+pub mod sensors;
+
+pub use registry::{CollectContext, Collector, CollectorRegistry, CollectorStatus, RefreshRequirements};
 
 // Platform-specific helpers should remain public if called from gpu.rs
 #[cfg(target_os = "linux")]
@@ -14,4 +23,31 @@ pub mod linux_gpu_helpers;
 #[cfg(target_os = "macos")]
 pub mod macos_gpu_helpers;
 #[cfg(target_os = "windows")]
-pub mod windows_gpu_helpers;
\ No newline at end of file
+pub mod windows_gpu_helpers;
+
+/// Built-in collectors in their default order. Order matters where a collector reads
+/// another's output (gpu's fallback simulation uses cpu_usage, so cpu runs first).
+pub fn builtin_collectors() -> Vec<Box<dyn Collector>> {
+    vec![
+        Box::new(cpu::CpuCollector::default()),
+        Box::new(memory::MemoryCollector::default()),
+        Box::new(cpu::ProcessCollector),
+        Box::new(disk::SystemDiskIoCollector::default()),
+        Box::new(network::NetworkCollector::default()),
+        Box::new(disk::DiskCollector::default()),
+        Box::new(gpu::GpuCollector::default()),
+        Box::new(sensors::SensorsCollector::default()),
+        Box::new(power::PowerCollector::default()),
+        Box::new(rapl::RaplCollector::default()),
+        Box::new(cgroups::CgroupsCollector::default()),
+        Box::new(pressure::PressureCollector::default()), // After cgroups, whose list it reads
+    ]
+}
+
+// Appends to a HISTORY_LENGTH-capped history buffer (values, or the epoch ms they were sampled at)
+pub(crate) fn push_history<T>(history: &mut Vec<T>, value: T) {
+    if history.len() >= crate::models::HISTORY_LENGTH {
+        history.remove(0);
+    }
+    history.push(value);
+}
\ No newline at end of file
@@ -1,11 +1,50 @@
 // TauriViteReact/src-tauri/src/collectors/network.rs (Corrected Check + Enhanced Logging)
 
-use crate::models::NetworkData;
+use crate::collectors::registry::{CollectContext, Collector, RefreshRequirements};
+use crate::models::{InterfaceCounters, InterfaceInfo, NetworkData, SystemData};
 use crate::utils::Result;
 use std::collections::HashMap;
 use std::time::Instant;
 use sysinfo::{NetworkExt, NetworksExt, System, SystemExt};
 
+#[cfg(target_os = "linux")]
+const SYS_CLASS_NET: &str = "/sys/class/net";
+
+/// Per-interface rx/tx rates and histories, link metadata and packet/error counters
+#[derive(Default)]
+pub struct NetworkCollector {
+    state: HashMap<String, NetworkData>,
+}
+
+impl Collector for NetworkCollector {
+    fn name(&self) -> &'static str { "network" }
+
+    fn refresh_requirements(&self) -> RefreshRequirements {
+        RefreshRequirements { networks: true, ..RefreshRequirements::NONE }
+    }
+
+    fn collect(&mut self, ctx: &CollectContext<'_>, data: &mut SystemData) -> Result<()> {
+        update_network_data(ctx.sys, &mut self.state, ctx.now)?;
+        let mut addresses = interface_addresses();
+        for (name, net) in self.state.iter_mut() {
+            let sysinfo_data = ctx.sys.networks().iter().find(|(n, _)| *n == name).map(|(_, d)| d);
+            net.info = read_interface_info(name, sysinfo_data, addresses.remove(name).unwrap_or_default());
+            let counters = read_interface_counters(name).or_else(|| sysinfo_data.map(sysinfo_counters));
+            if let Some(counters) = counters {
+                update_counters(net, counters, ctx.now);
+            }
+            // update() added one rx/tx sample; keep every history at one sample per cycle
+            super::push_history(&mut net.error_history, net.rates.rx_errors + net.rates.tx_errors);
+            super::push_history(&mut net.drop_history, net.rates.rx_dropped + net.rates.tx_dropped);
+            super::push_history(&mut net.history_timestamps, ctx.timestamp.timestamp_millis());
+        }
+        data.network_data = self.state.clone();
+        Ok(())
+    }
+
+    fn reset_state(&mut self) { self.state.clear(); }
+}
+
 pub fn update_network_data(
     system: &System,
     network_data_map: &mut HashMap<String, NetworkData>,
@@ -60,4 +99,143 @@ pub fn update_network_data(
     log::debug!("Network data map finalized with {} entries.", network_data_map.len());
 
     Ok(())
-}
\ No newline at end of file
+}
+
+// --- Link metadata ---
+
+fn read_interface_info(name: &str, sysinfo_data: Option<&sysinfo::NetworkData>, addresses: (Vec<String>, Vec<String>)) -> InterfaceInfo {
+    let (ipv4_addresses, ipv6_addresses) = addresses;
+    let mut info = InterfaceInfo {
+        mac_address: sysinfo_data.map(|d| d.mac_address()).filter(|mac| !mac.is_unspecified()).map(|mac| mac.to_string()),
+        ipv4_addresses,
+        ipv6_addresses,
+        ..InterfaceInfo::default()
+    };
+    #[cfg(target_os = "linux")]
+    {
+        let dir = std::path::Path::new(SYS_CLASS_NET).join(name);
+        // speed/duplex fail with EINVAL on links that are down or virtual; speed may also read -1
+        let read = |file: &str| std::fs::read_to_string(dir.join(file)).ok().map(|s| s.trim().to_string()).filter(|s| !s.is_empty());
+        info.mtu = read("mtu").and_then(|v| v.parse().ok());
+        info.operstate = read("operstate");
+        info.speed_mbps = read("speed").and_then(|v| v.parse::<i64>().ok()).filter(|v| *v > 0).map(|v| v as u32);
+        info.duplex = read("duplex").filter(|v| v != "unknown");
+        info.driver = std::fs::read_link(dir.join("device/driver"))
+            .ok()
+            .and_then(|driver| driver.file_name().map(|n| n.to_string_lossy().into_owned()));
+    }
+    #[cfg(not(target_os = "linux"))]
+    let _ = name;
+    info
+}
+
+// Interface name -> (IPv4, IPv6) addresses in CIDR notation
+#[cfg(unix)]
+fn interface_addresses() -> HashMap<String, (Vec<String>, Vec<String>)> {
+    use std::ffi::CStr;
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    let mut addresses: HashMap<String, (Vec<String>, Vec<String>)> = HashMap::new();
+    let mut ifap: *mut libc::ifaddrs = std::ptr::null_mut();
+    // SAFETY: getifaddrs fills `ifap` with a linked list we free below; every pointer in it
+    // stays valid until freeifaddrs, and sockaddr casts follow the sa_family tag.
+    unsafe {
+        if libc::getifaddrs(&mut ifap) != 0 {
+            log::debug!("getifaddrs failed: {}", std::io::Error::last_os_error());
+            return addresses;
+        }
+        let mut cursor = ifap;
+        while let Some(ifa) = cursor.as_ref() {
+            cursor = ifa.ifa_next;
+            if ifa.ifa_addr.is_null() || ifa.ifa_name.is_null() {
+                continue;
+            }
+            let name = CStr::from_ptr(ifa.ifa_name).to_string_lossy().into_owned();
+            match (*ifa.ifa_addr).sa_family as i32 {
+                libc::AF_INET => {
+                    let addr = &*(ifa.ifa_addr as *const libc::sockaddr_in);
+                    let prefix = ifa.ifa_netmask.as_ref().map_or(32, |mask| {
+                        (*(mask as *const libc::sockaddr as *const libc::sockaddr_in)).sin_addr.s_addr.count_ones()
+                    });
+                    let ip = Ipv4Addr::from(u32::from_be(addr.sin_addr.s_addr));
+                    addresses.entry(name).or_default().0.push(format!("{}/{}", ip, prefix));
+                }
+                libc::AF_INET6 => {
+                    let addr = &*(ifa.ifa_addr as *const libc::sockaddr_in6);
+                    let prefix = ifa.ifa_netmask.as_ref().map_or(128, |mask| {
+                        let mask = &*(mask as *const libc::sockaddr as *const libc::sockaddr_in6);
+                        mask.sin6_addr.s6_addr.iter().map(|b| b.count_ones()).sum()
+                    });
+                    let ip = Ipv6Addr::from(addr.sin6_addr.s6_addr);
+                    addresses.entry(name).or_default().1.push(format!("{}/{}", ip, prefix));
+                }
+                _ => {} // AF_PACKET / AF_LINK entries carry the MAC, which sysinfo already reports
+            }
+        }
+        libc::freeifaddrs(ifap);
+    }
+    addresses
+}
+
+#[cfg(not(unix))]
+fn interface_addresses() -> HashMap<String, (Vec<String>, Vec<String>)> {
+    HashMap::new()
+}
+
+// --- Packet / error counters ---
+
+#[cfg(target_os = "linux")]
+fn read_interface_counters(name: &str) -> Option<InterfaceCounters<u64>> {
+    let dir = std::path::Path::new(SYS_CLASS_NET).join(name).join("statistics");
+    let read = |file: &str| -> Option<u64> { std::fs::read_to_string(dir.join(file)).ok()?.trim().parse().ok() };
+    Some(InterfaceCounters {
+        rx_packets: read("rx_packets")?,
+        tx_packets: read("tx_packets")?,
+        rx_errors: read("rx_errors")?,
+        tx_errors: read("tx_errors")?,
+        rx_dropped: read("rx_dropped")?,
+        tx_dropped: read("tx_dropped")?,
+        collisions: read("collisions").unwrap_or(0),
+        multicast: read("multicast").unwrap_or(0),
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_interface_counters(_name: &str) -> Option<InterfaceCounters<u64>> {
+    None
+}
+
+// Elsewhere sysinfo has packets and errors only
+fn sysinfo_counters(data: &sysinfo::NetworkData) -> InterfaceCounters<u64> {
+    InterfaceCounters {
+        rx_packets: data.total_packets_received(),
+        tx_packets: data.total_packets_transmitted(),
+        rx_errors: data.total_errors_on_received(),
+        tx_errors: data.total_errors_on_transmitted(),
+        ..InterfaceCounters::default()
+    }
+}
+
+fn update_counters(net: &mut NetworkData, counters: InterfaceCounters<u64>, now: Instant) {
+    net.rates = match net.last_counters {
+        Some((last, last_time)) if now.duration_since(last_time).as_secs_f64() > 0.001 => {
+            let elapsed = now.duration_since(last_time).as_secs_f64();
+            // saturating_sub: counters restart from zero when a driver resets the device
+            let rate = |current: u64, previous: u64| current.saturating_sub(previous) as f64 / elapsed;
+            InterfaceCounters {
+                rx_packets: rate(counters.rx_packets, last.rx_packets),
+                tx_packets: rate(counters.tx_packets, last.tx_packets),
+                rx_errors: rate(counters.rx_errors, last.rx_errors),
+                tx_errors: rate(counters.tx_errors, last.tx_errors),
+                rx_dropped: rate(counters.rx_dropped, last.rx_dropped),
+                tx_dropped: rate(counters.tx_dropped, last.tx_dropped),
+                collisions: rate(counters.collisions, last.collisions),
+                multicast: rate(counters.multicast, last.multicast),
+            }
+        }
+        Some(_) => net.rates, // Interval too short to measure, keep the last rates
+        None => InterfaceCounters::default(),
+    };
+    net.counters = counters;
+    net.last_counters = Some((counters, now));
+}
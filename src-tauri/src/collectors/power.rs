@@ -0,0 +1,194 @@
+// TauriViteReact/src-tauri/src/collectors/power.rs
+// Power supplies from /sys/class/power_supply: AC adapter state and, per battery, charge level,
+// energy, charge/discharge power, wear and cycle count. Time to empty/full comes from the
+// energy trend over the last few minutes, which is steadier than the instantaneous power draw.
+
+use crate::collectors::push_history;
+use crate::collectors::registry::{CollectContext, Collector};
+use crate::models::{BatteryInfo, PowerData, SystemData};
+use crate::utils::error::Result;
+use std::collections::{HashMap, VecDeque};
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+pub const POWER_SUPPLY_ROOT: &str = "/sys/class/power_supply";
+
+// Many firmwares only update energy_now every 30-60s, so the trend needs a few minutes
+const TREND_WINDOW: Duration = Duration::from_secs(300);
+const MIN_TREND_SPAN: Duration = Duration::from_secs(30);
+
+// Per-battery state kept between cycles
+#[derive(Default)]
+struct BatteryState {
+    status: String,
+    trend: VecDeque<(Instant, f64)>, // (sampled at, energy_now_wh) since the status last changed
+    capacity_history: Vec<f64>,
+    power_history: Vec<f64>,
+    history_timestamps: Vec<i64>,
+}
+
+/// AC state and batteries, with histories and the energy trend per battery
+#[derive(Default)]
+pub struct PowerCollector {
+    batteries: HashMap<String, BatteryState>,
+}
+
+impl Collector for PowerCollector {
+    fn name(&self) -> &'static str { "power" }
+
+    // Reads sysfs directly, no sysinfo refresh needed
+    fn collect(&mut self, ctx: &CollectContext<'_>, data: &mut SystemData) -> Result<()> {
+        let Some(mut power) = read_power_supplies(Path::new(POWER_SUPPLY_ROOT)) else {
+            self.batteries.clear();
+            data.power = None;
+            return Ok(());
+        };
+
+        for battery in &mut power.batteries {
+            let state = self.batteries.entry(battery.name.clone()).or_default();
+            update_battery(state, battery, ctx.now, ctx.timestamp.timestamp_millis());
+        }
+        // Forget batteries that were removed
+        self.batteries.retain(|name, _| power.batteries.iter().any(|b| &b.name == name));
+
+        data.power = Some(power);
+        Ok(())
+    }
+
+    fn reset_state(&mut self) { self.batteries.clear(); }
+}
+
+fn update_battery(state: &mut BatteryState, battery: &mut BatteryInfo, now: Instant, now_ms: i64) {
+    // A trend from before plugging in or out says nothing about the new direction
+    if state.status != battery.status {
+        state.status = battery.status.clone();
+        state.trend.clear();
+    }
+    if let Some(energy) = battery.energy_now_wh {
+        state.trend.push_back((now, energy));
+    }
+    while state.trend.front().is_some_and(|(at, _)| now.duration_since(*at) > TREND_WINDOW) {
+        state.trend.pop_front();
+    }
+
+    // Energy trend in Wh per hour, i.e. watts; positive while charging
+    let rate_w = energy_trend_w(&state.trend).or(battery.power_w).filter(|rate| rate.abs() > 0.01);
+    let seconds_until = |energy_wh: f64, rate_w: f64| Some((energy_wh / rate_w * 3600.0).round() as u64);
+    match (battery.status.as_str(), rate_w) {
+        ("Discharging", Some(rate)) if rate < 0.0 => {
+            battery.time_to_empty_secs = battery.energy_now_wh.and_then(|now| seconds_until(now, -rate));
+        }
+        ("Charging", Some(rate)) if rate > 0.0 => {
+            battery.time_to_full_secs = battery
+                .energy_now_wh
+                .zip(battery.energy_full_wh)
+                .and_then(|(now, full)| seconds_until((full - now).max(0.0), rate));
+        }
+        _ => {}
+    }
+
+    push_history(&mut state.capacity_history, battery.capacity_percent.unwrap_or(0.0));
+    push_history(&mut state.power_history, battery.power_w.unwrap_or(0.0));
+    push_history(&mut state.history_timestamps, now_ms);
+    battery.capacity_history = state.capacity_history.clone();
+    battery.power_history = state.power_history.clone();
+    battery.history_timestamps = state.history_timestamps.clone();
+}
+
+// Least-squares slope of energy over time, in watts; None until the samples span MIN_TREND_SPAN
+// or while energy_now hasn't moved (coarse firmware updates)
+fn energy_trend_w(trend: &VecDeque<(Instant, f64)>) -> Option<f64> {
+    let (first, _) = trend.front()?;
+    let (last, _) = trend.back()?;
+    if last.duration_since(*first) < MIN_TREND_SPAN {
+        return None;
+    }
+    let points: Vec<(f64, f64)> = trend.iter().map(|(at, energy)| (at.duration_since(*first).as_secs_f64() / 3600.0, *energy)).collect();
+    let n = points.len() as f64;
+    let mean_t = points.iter().map(|(t, _)| t).sum::<f64>() / n;
+    let mean_e = points.iter().map(|(_, e)| e).sum::<f64>() / n;
+    let covariance: f64 = points.iter().map(|(t, e)| (t - mean_t) * (e - mean_e)).sum();
+    let variance: f64 = points.iter().map(|(t, _)| (t - mean_t).powi(2)).sum();
+    let slope = covariance / variance;
+    (slope != 0.0 && slope.is_finite()).then_some(slope)
+}
+
+// --- sysfs ---
+
+fn read_trimmed(path: &Path) -> Option<String> {
+    fs::read_to_string(path).ok().map(|s| s.trim().to_string()).filter(|s| !s.is_empty())
+}
+
+// Values are in µWh, µAh, µW, µA and µV; some drivers fail reads with ENODATA while unplugged
+fn read_micro(dir: &Path, attribute: &str) -> Option<f64> {
+    read_trimmed(&dir.join(attribute))?.parse::<f64>().ok().map(|v| v / 1_000_000.0)
+}
+
+/// Every supply under `root` (normally /sys/class/power_supply); None if there are none at all.
+pub fn read_power_supplies(root: &Path) -> Option<PowerData> {
+    let mut entries: Vec<_> = fs::read_dir(root).ok()?.flatten().collect();
+    entries.sort_by_key(|entry| entry.file_name());
+    if entries.is_empty() {
+        return None;
+    }
+
+    let mut power = PowerData::default();
+    for entry in entries {
+        let dir = entry.path();
+        let name = entry.file_name().to_string_lossy().into_owned();
+        match read_trimmed(&dir.join("type")).as_deref() {
+            Some("Mains" | "USB") => {
+                let online = read_trimmed(&dir.join("online")).as_deref() == Some("1");
+                power.ac_online = Some(power.ac_online.unwrap_or(false) || online);
+            }
+            // scope=Device marks peripheral batteries (mice, keyboards, headsets)
+            Some("Battery")
+                if read_trimmed(&dir.join("scope")).as_deref() != Some("Device")
+                    && read_trimmed(&dir.join("present")).as_deref() != Some("0") =>
+            {
+                power.batteries.push(read_battery(&dir, name));
+            }
+            _ => {}
+        }
+    }
+    Some(power)
+}
+
+fn read_battery(dir: &Path, name: String) -> BatteryInfo {
+    let voltage = read_micro(dir, "voltage_now");
+    // Charge-reporting batteries (µAh/µA) are converted with the design voltage where known,
+    // like upower does, since voltage_now sags under load
+    let nominal_voltage = read_micro(dir, "voltage_min_design").or(voltage);
+    let energy = |kind: &str| {
+        read_micro(dir, &format!("energy_{}", kind))
+            .or_else(|| Some(read_micro(dir, &format!("charge_{}", kind))? * nominal_voltage?))
+    };
+    let energy_now_wh = energy("now");
+    let energy_full_wh = energy("full");
+    let energy_full_design_wh = energy("full_design");
+
+    let status = read_trimmed(&dir.join("status")).unwrap_or_else(|| "Unknown".to_string());
+    // power_now is unsigned on most drivers; the status gives the direction
+    let power = read_micro(dir, "power_now").or_else(|| Some(read_micro(dir, "current_now")? * voltage?)).map(f64::abs);
+    let power_w = power.map(|watts| if status == "Discharging" { -watts } else { watts });
+
+    BatteryInfo {
+        manufacturer: read_trimmed(&dir.join("manufacturer")),
+        model_name: read_trimmed(&dir.join("model_name")),
+        technology: read_trimmed(&dir.join("technology")),
+        capacity_percent: read_trimmed(&dir.join("capacity"))
+            .and_then(|v| v.parse().ok())
+            .or_else(|| Some(energy_now_wh? / energy_full_wh? * 100.0)),
+        health_percent: energy_full_wh.zip(energy_full_design_wh).filter(|(_, design)| *design > 0.0).map(|(full, design)| full / design * 100.0),
+        cycle_count: read_trimmed(&dir.join("cycle_count")).and_then(|v| v.parse().ok()),
+        voltage_v: voltage,
+        name,
+        status,
+        energy_now_wh,
+        energy_full_wh,
+        energy_full_design_wh,
+        power_w,
+        ..BatteryInfo::default()
+    }
+}
@@ -0,0 +1,132 @@
+// TauriViteReact/src-tauri/src/collectors/pressure.rs
+// Pressure Stall Information: how much time tasks spent waiting for CPU, memory and I/O,
+// system-wide from /proc/pressure and per cgroup from <cgroup>/{cpu,memory,io}.pressure.
+// Unlike utilization, this shows contention directly.
+
+use crate::collectors::cgroups::{unified_root, CGROUP_ROOT};
+use crate::collectors::push_history;
+use crate::collectors::registry::{CollectContext, Collector};
+use crate::models::{CgroupPressure, PressureData, PressureStat, ResourcePressure, SystemData};
+use crate::utils::error::Result;
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::Instant;
+
+pub const PROC_PRESSURE: &str = "/proc/pressure";
+
+const RESOURCES: [&str; 3] = ["cpu", "memory", "io"];
+
+/// System-wide and per-cgroup pressure. The previous sample of every file is kept, keyed by
+/// (cgroup path, resource) with "" for the system-wide files, for stall rates and histories.
+#[derive(Default)]
+pub struct PressureCollector {
+    last: HashMap<(String, &'static str), (ResourcePressure, Instant)>,
+}
+
+impl Collector for PressureCollector {
+    fn name(&self) -> &'static str { "pressure" }
+
+    // Runs after the cgroups collector and reads pressure for the cgroups it found
+    fn collect(&mut self, ctx: &CollectContext<'_>, data: &mut SystemData) -> Result<()> {
+        if !Path::new(PROC_PRESSURE).is_dir() {
+            data.pressure = None;
+            return Ok(());
+        }
+
+        let mut current = HashMap::new();
+        let mut sample = |path: &str, resource: &'static str, file: &Path| -> Option<ResourcePressure> {
+            let parsed = parse_pressure(&std::fs::read_to_string(file).ok()?)?;
+            let key = (path.to_string(), resource);
+            let pressure = update_pressure(parsed, self.last.get(&key), ctx.now, ctx.timestamp.timestamp_millis());
+            current.insert(key, (pressure.clone(), ctx.now));
+            Some(pressure)
+        };
+
+        let mut system = [None, None, None];
+        for (slot, resource) in system.iter_mut().zip(RESOURCES) {
+            *slot = sample("", resource, &Path::new(PROC_PRESSURE).join(resource));
+        }
+        let [cpu, memory, io] = system;
+
+        // Only headline cgroups, so the list stays short and stable; files are missing when
+        // the kernel runs with cgroup PSI disabled
+        let mut cgroups = Vec::new();
+        if let Some(root) = unified_root(Path::new(CGROUP_ROOT)) {
+            for cgroup in data.cgroups.iter().filter(|c| c.is_headline()) {
+                let dir = root.join(cgroup.path.trim_start_matches('/'));
+                let mut files = [None, None, None];
+                for (slot, resource) in files.iter_mut().zip(RESOURCES) {
+                    *slot = sample(&cgroup.path, resource, &dir.join(format!("{}.pressure", resource)));
+                }
+                if files.iter().all(Option::is_none) {
+                    continue;
+                }
+                let [cpu, memory, io] = files;
+                cgroups.push(CgroupPressure { path: cgroup.path.clone(), cpu, memory, io });
+            }
+        }
+        // Also forgets cgroups that went away
+        self.last = current;
+
+        data.pressure = Some(PressureData { cpu, memory, io, cgroups });
+        Ok(())
+    }
+
+    fn reset_state(&mut self) { self.last.clear(); }
+}
+
+/// Parses a pressure file:
+/// ```text
+/// some avg10=1.74 avg60=2.59 avg300=2.04 total=67852212
+/// full avg10=0.00 avg60=0.00 avg300=0.00 total=0
+/// ```
+pub fn parse_pressure(content: &str) -> Option<ResourcePressure> {
+    let mut some = None;
+    let mut full = None;
+    for line in content.lines() {
+        let Some((kind, fields)) = line.split_once(' ') else { continue };
+        let mut stat = PressureStat::default();
+        for field in fields.split_whitespace() {
+            let Some((key, value)) = field.split_once('=') else { continue };
+            match key {
+                "avg10" => stat.avg10 = value.parse().unwrap_or(0.0),
+                "avg60" => stat.avg60 = value.parse().unwrap_or(0.0),
+                "avg300" => stat.avg300 = value.parse().unwrap_or(0.0),
+                "total" => stat.total_us = value.parse().unwrap_or(0),
+                _ => {}
+            }
+        }
+        match kind {
+            "some" => some = Some(stat),
+            "full" => full = Some(stat),
+            _ => {}
+        }
+    }
+    Some(ResourcePressure { some: some?, full })
+}
+
+// Fills stall_percent from the total_us delta and carries the history over from the previous sample
+fn update_pressure(
+    mut pressure: ResourcePressure,
+    previous: Option<&(ResourcePressure, Instant)>,
+    now: Instant,
+    now_ms: i64,
+) -> ResourcePressure {
+    let Some((previous, at)) = previous else { return pressure };
+    let elapsed_us = now.duration_since(*at).as_secs_f64() * 1_000_000.0;
+    let update = |stat: &mut PressureStat, before: &PressureStat| {
+        stat.history = before.history.clone();
+        stat.history_timestamps = before.history_timestamps.clone();
+        if elapsed_us > 1000.0 {
+            // Stall time can't exceed wall time; the clamp hides sampling jitter
+            stat.stall_percent = (stat.total_us.saturating_sub(before.total_us) as f64 / elapsed_us * 100.0).clamp(0.0, 100.0);
+        }
+        push_history(&mut stat.history, stat.stall_percent);
+        push_history(&mut stat.history_timestamps, now_ms);
+    };
+    update(&mut pressure.some, &previous.some);
+    if let (Some(full), Some(before)) = (pressure.full.as_mut(), previous.full.as_ref()) {
+        update(full, before);
+    }
+    pressure
+}
@@ -0,0 +1,377 @@
+// TauriViteReact/src-tauri/src/collectors/processes.rs
+// Full process table and process tree for the Processes tab: every process, sorted,
+// filtered and paged on the backend. Reads the sysinfo state the "processes" collector
+// refreshes each cycle.
+
+use crate::collectors::cgroups;
+use crate::models::ContainerRef;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use sysinfo::{Pid, PidExt, Process, ProcessExt, System, SystemExt, UserExt};
+
+pub const DEFAULT_PAGE_SIZE: usize = 100;
+
+/// One row of the process table
+#[derive(Serialize, Clone, Debug)]
+pub struct ProcessInfo {
+    pub pid: u32,
+    pub parent_pid: Option<u32>,
+    pub name: String,
+    pub cmd: String,            // Full command line, empty for kernel threads
+    pub user: Option<String>,   // Owning user name, None if unknown
+    pub status: String,
+    pub cpu_usage: f32,         // Percent of one core, can exceed 100 for multi-threaded processes
+    pub memory_mb: f64,         // Resident set size
+    pub virtual_memory_mb: f64,
+    pub start_time: u64,        // Seconds since epoch
+    pub run_time: u64,          // Seconds
+    pub cgroup: Option<String>, // cgroup v2 path; only filled for the rows of the returned page
+    pub container: Option<ContainerRef>,
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ProcessSortKey {
+    Pid,
+    Name,
+    #[default]
+    Cpu,
+    Memory,
+    VirtualMemory,
+    User,
+    Status,
+    StartTime,
+}
+
+/// Arguments of `get_process_table`; every field is optional on the wire.
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct ProcessQuery {
+    pub sort_by: ProcessSortKey,
+    pub descending: bool,
+    pub filter: Option<String>, // Case-insensitive match on name, command line or user; or a PID prefix
+    pub offset: usize,
+    pub limit: Option<usize>,   // None: DEFAULT_PAGE_SIZE, Some(0): everything
+}
+
+impl Default for ProcessQuery {
+    fn default() -> Self {
+        ProcessQuery { sort_by: ProcessSortKey::Cpu, descending: true, filter: None, offset: 0, limit: None }
+    }
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct ProcessPage {
+    pub total: usize,   // All processes
+    pub matched: usize, // Processes matching the filter
+    pub offset: usize,
+    pub processes: Vec<ProcessInfo>,
+}
+
+fn process_info(system: &System, process: &Process) -> ProcessInfo {
+    let user = process
+        .user_id()
+        .and_then(|uid| system.get_user_by_id(uid))
+        .map(|user| user.name().to_string());
+    ProcessInfo {
+        pid: process.pid().as_u32(),
+        parent_pid: process.parent().map(|pid| pid.as_u32()),
+        name: process.name().to_string(),
+        cmd: process.cmd().join(" "),
+        user,
+        status: process.status().to_string(),
+        cpu_usage: process.cpu_usage(),
+        memory_mb: process.memory() as f64 / (1024.0 * 1024.0),
+        virtual_memory_mb: process.virtual_memory() as f64 / (1024.0 * 1024.0),
+        start_time: process.start_time(),
+        run_time: process.run_time(),
+        cgroup: None,
+        container: None,
+    }
+}
+
+fn matches_filter(info: &ProcessInfo, needle: &str) -> bool {
+    info.pid.to_string().starts_with(needle)
+        || info.name.to_lowercase().contains(needle)
+        || info.cmd.to_lowercase().contains(needle)
+        || info.user.as_deref().is_some_and(|user| user.to_lowercase().contains(needle))
+}
+
+fn compare(a: &ProcessInfo, b: &ProcessInfo, key: ProcessSortKey) -> Ordering {
+    match key {
+        ProcessSortKey::Pid => a.pid.cmp(&b.pid),
+        ProcessSortKey::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+        ProcessSortKey::Cpu => a.cpu_usage.total_cmp(&b.cpu_usage),
+        ProcessSortKey::Memory => a.memory_mb.total_cmp(&b.memory_mb),
+        ProcessSortKey::VirtualMemory => a.virtual_memory_mb.total_cmp(&b.virtual_memory_mb),
+        ProcessSortKey::User => a.user.cmp(&b.user),
+        ProcessSortKey::Status => a.status.cmp(&b.status),
+        ProcessSortKey::StartTime => a.start_time.cmp(&b.start_time),
+    }
+}
+
+/// Builds one page of the process table from already refreshed sysinfo data.
+pub fn query_processes(system: &System, query: &ProcessQuery) -> ProcessPage {
+    let total = system.processes().len();
+    let needle = query.filter.as_deref().map(|f| f.trim().to_lowercase()).filter(|f| !f.is_empty());
+
+    let mut rows: Vec<ProcessInfo> = system
+        .processes()
+        .values()
+        .map(|process| process_info(system, process))
+        .filter(|info| needle.as_deref().is_none_or(|needle| matches_filter(info, needle)))
+        .collect();
+
+    // PID as tie-breaker keeps the order stable between refreshes
+    rows.sort_by(|a, b| {
+        let ordering = compare(a, b, query.sort_by);
+        let ordering = if query.descending { ordering.reverse() } else { ordering };
+        ordering.then(a.pid.cmp(&b.pid))
+    });
+
+    let matched = rows.len();
+    let limit = match query.limit {
+        Some(0) => usize::MAX,
+        Some(limit) => limit,
+        None => DEFAULT_PAGE_SIZE,
+    };
+    // One /proc read per row, so only for the page actually returned
+    let processes = rows
+        .into_iter()
+        .skip(query.offset)
+        .take(limit)
+        .map(|mut info| {
+            info.cgroup = cgroups::process_cgroup(info.pid);
+            info.container = info.cgroup.as_deref().and_then(cgroups::detect_container);
+            info
+        })
+        .collect();
+    ProcessPage { total, matched, offset: query.offset, processes }
+}
+
+/// The processes of one cgroup subtree (e.g. a systemd unit), busiest first.
+pub fn processes_in_cgroup(system: &System, cgroup_path: &str) -> Vec<ProcessInfo> {
+    let mut rows: Vec<ProcessInfo> = cgroups::subtree_pids(cgroup_path)
+        .into_iter()
+        .filter_map(|pid| system.process(Pid::from_u32(pid)))
+        .map(|process| {
+            let mut info = process_info(system, process);
+            info.cgroup = cgroups::process_cgroup(info.pid);
+            info.container = info.cgroup.as_deref().and_then(cgroups::detect_container);
+            info
+        })
+        .collect();
+    rows.sort_by(|a, b| b.cpu_usage.total_cmp(&a.cpu_usage).then(a.pid.cmp(&b.pid)));
+    rows
+}
+
+// --- Process tree ---
+
+/// A process with its children. Subtree totals include the process itself; memory is a
+/// plain RSS sum, so pages shared between parent and children are counted more than once.
+#[derive(Serialize, Clone, Debug)]
+pub struct ProcessTreeNode {
+    pub pid: u32,
+    pub parent_pid: Option<u32>,
+    pub name: String,
+    pub cpu_usage: f32,
+    pub memory_mb: f64,
+    pub subtree_cpu_usage: f32,
+    pub subtree_memory_mb: f64,
+    pub child_count: usize,      // Direct children
+    pub descendant_count: usize, // All processes below this one
+    pub collapsed: bool,         // Children omitted (totals still include them)
+    pub children: Vec<ProcessTreeNode>,
+}
+
+/// Builds the process forest (one root per process without a live parent, e.g. init and
+/// kthreadd). Children of PIDs in `collapsed` are left out; siblings are ordered by subtree CPU.
+pub fn build_process_tree(system: &System, collapsed: &HashSet<u32>) -> Vec<ProcessTreeNode> {
+    let processes = system.processes();
+    let mut children: HashMap<u32, Vec<u32>> = HashMap::new();
+    let mut roots = Vec::new();
+    for (pid, process) in processes {
+        match process.parent().filter(|parent| processes.contains_key(parent)) {
+            Some(parent) => children.entry(parent.as_u32()).or_default().push(pid.as_u32()),
+            None => roots.push(pid.as_u32()),
+        }
+    }
+
+    let mut visited = HashSet::new();
+    let mut tree: Vec<ProcessTreeNode> = roots
+        .into_iter()
+        .filter_map(|pid| build_node(system, pid, &children, collapsed, &mut visited))
+        .collect();
+    // Processes only reachable through a parent cycle (PID reuse mid-refresh) become roots too
+    let mut unreached: Vec<u32> = processes.keys().map(|pid| pid.as_u32()).filter(|pid| !visited.contains(pid)).collect();
+    unreached.sort_unstable();
+    for pid in unreached {
+        tree.extend(build_node(system, pid, &children, collapsed, &mut visited));
+    }
+    sort_siblings(&mut tree);
+    tree
+}
+
+fn build_node(
+    system: &System,
+    pid: u32,
+    children: &HashMap<u32, Vec<u32>>,
+    collapsed: &HashSet<u32>,
+    visited: &mut HashSet<u32>,
+) -> Option<ProcessTreeNode> {
+    // Guards against parent cycles from PID reuse between refreshes
+    if !visited.insert(pid) {
+        return None;
+    }
+    let process = system.process(Pid::from_u32(pid))?;
+    let mut child_nodes: Vec<ProcessTreeNode> = children
+        .get(&pid)
+        .into_iter()
+        .flatten()
+        .filter_map(|child| build_node(system, *child, children, collapsed, visited))
+        .collect();
+    sort_siblings(&mut child_nodes);
+
+    let memory_mb = process.memory() as f64 / (1024.0 * 1024.0);
+    let is_collapsed = collapsed.contains(&pid) && !child_nodes.is_empty();
+    let node = ProcessTreeNode {
+        pid,
+        parent_pid: process.parent().map(|parent| parent.as_u32()),
+        name: process.name().to_string(),
+        cpu_usage: process.cpu_usage(),
+        memory_mb,
+        subtree_cpu_usage: process.cpu_usage() + child_nodes.iter().map(|c| c.subtree_cpu_usage).sum::<f32>(),
+        subtree_memory_mb: memory_mb + child_nodes.iter().map(|c| c.subtree_memory_mb).sum::<f64>(),
+        child_count: child_nodes.len(),
+        descendant_count: child_nodes.iter().map(|c| c.descendant_count + 1).sum(),
+        collapsed: is_collapsed,
+        children: if is_collapsed { Vec::new() } else { child_nodes },
+    };
+    Some(node)
+}
+
+fn sort_siblings(nodes: &mut [ProcessTreeNode]) {
+    nodes.sort_by(|a, b| b.subtree_cpu_usage.total_cmp(&a.subtree_cpu_usage).then(a.pid.cmp(&b.pid)));
+}
+
+// --- Process details ---
+
+/// Everything known about one process, for the detail inspector. Fields that need
+/// /proc are None on other platforms or when the process belongs to another user.
+#[derive(Serialize, Clone, Debug)]
+pub struct ProcessDetails {
+    pub pid: u32,
+    pub parent_pid: Option<u32>,
+    pub name: String,
+    pub cmd: Vec<String>,       // Command line as separate arguments
+    pub exe: Option<String>,
+    pub cwd: Option<String>,
+    pub environ: Vec<String>,   // KEY=value entries
+    pub user: Option<String>,
+    pub uid: Option<u32>,
+    pub status: String,
+    pub start_time: u64,        // Seconds since epoch
+    pub run_time: u64,          // Seconds
+    pub cpu_usage: f32,
+    pub memory_mb: f64,
+    pub virtual_memory_mb: f64,
+    pub session_id: Option<u32>,
+    pub process_group: Option<u32>,
+    pub thread_count: Option<u32>,
+    pub nice: Option<i32>,
+    pub priority: Option<i32>,
+    pub open_files: Option<usize>,
+    pub cgroup: Option<String>,
+    pub container: Option<ContainerRef>,
+}
+
+// Paths sysinfo could not read come back empty
+fn non_empty_path(path: &std::path::Path) -> Option<String> {
+    let path = path.to_string_lossy();
+    (!path.is_empty()).then(|| path.into_owned())
+}
+
+/// Details for `pid` from already refreshed sysinfo data plus /proc. None if the process is gone.
+pub fn process_details(system: &System, pid: u32) -> Option<ProcessDetails> {
+    let process = system.process(Pid::from_u32(pid))?;
+    #[cfg(target_os = "linux")]
+    let stat = read_proc_stat(pid);
+    #[cfg(target_os = "linux")]
+    let (process_group, thread_count, nice, priority) = (
+        stat.as_ref().map(|s| s.process_group),
+        stat.as_ref().map(|s| s.thread_count),
+        stat.as_ref().map(|s| s.nice),
+        stat.as_ref().map(|s| s.priority),
+    );
+    #[cfg(not(target_os = "linux"))]
+    let (process_group, thread_count, nice, priority) = (None, None, None, None);
+    let cgroup = cgroups::process_cgroup(pid);
+    #[cfg(unix)]
+    let uid = process.user_id().map(|uid| **uid);
+    #[cfg(not(unix))]
+    let uid = None;
+
+    Some(ProcessDetails {
+        pid,
+        parent_pid: process.parent().map(|parent| parent.as_u32()),
+        name: process.name().to_string(),
+        cmd: process.cmd().to_vec(),
+        exe: non_empty_path(process.exe()),
+        cwd: non_empty_path(process.cwd()),
+        environ: process.environ().to_vec(),
+        user: process
+            .user_id()
+            .and_then(|uid| system.get_user_by_id(uid))
+            .map(|user| user.name().to_string()),
+        uid,
+        status: process.status().to_string(),
+        start_time: process.start_time(),
+        run_time: process.run_time(),
+        cpu_usage: process.cpu_usage(),
+        memory_mb: process.memory() as f64 / (1024.0 * 1024.0),
+        virtual_memory_mb: process.virtual_memory() as f64 / (1024.0 * 1024.0),
+        session_id: process.session_id().map(|sid| sid.as_u32()),
+        process_group,
+        thread_count,
+        nice,
+        priority,
+        open_files: count_open_files(pid),
+        container: cgroup.as_deref().and_then(cgroups::detect_container),
+        cgroup,
+    })
+}
+
+// The /proc/<pid>/stat fields sysinfo does not expose
+#[cfg(target_os = "linux")]
+struct ProcStat {
+    process_group: u32,
+    priority: i32,
+    nice: i32,
+    thread_count: u32,
+}
+
+#[cfg(target_os = "linux")]
+fn read_proc_stat(pid: u32) -> Option<ProcStat> {
+    let content = std::fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?;
+    // comm may contain spaces and parentheses; the fixed fields start after the last ')'
+    let fields: Vec<&str> = content.get(content.rfind(')')? + 1..)?.split_whitespace().collect();
+    // fields[0] is state (field 3 in proc(5)), so field N is fields[N - 3]
+    Some(ProcStat {
+        process_group: fields.get(2)?.parse().ok()?,
+        priority: fields.get(15)?.parse().ok()?,
+        nice: fields.get(16)?.parse().ok()?,
+        thread_count: fields.get(17)?.parse().ok()?,
+    })
+}
+
+#[cfg(target_os = "linux")]
+fn count_open_files(pid: u32) -> Option<usize> {
+    // Only readable for our own processes unless running as root
+    Some(std::fs::read_dir(format!("/proc/{}/fd", pid)).ok()?.count())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn count_open_files(_pid: u32) -> Option<usize> {
+    None
+}
@@ -0,0 +1,152 @@
+// TauriViteReact/src-tauri/src/collectors/rapl.rs
+// CPU-side power draw from the RAPL energy counters exposed through powercap (Intel, and AMD
+// Zen under the same "intel-rapl" name): package, core, uncore, dram and psys domains.
+// The counters are cumulative microjoules, so power is the delta over the sampling interval.
+
+use crate::collectors::push_history;
+use crate::collectors::registry::{CollectContext, Collector};
+use crate::models::{CpuPowerData, RaplDomain, SystemData};
+use crate::utils::error::Result;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::time::Instant;
+
+pub const POWERCAP_ROOT: &str = "/sys/class/powercap";
+
+// Previous reading of one domain
+struct DomainState {
+    energy_uj: u64,
+    at: Instant,
+    history: Vec<f64>,
+    history_timestamps: Vec<i64>,
+}
+
+/// Per-domain power from energy deltas, plus the package total history
+#[derive(Default)]
+pub struct RaplCollector {
+    domains: HashMap<String, DomainState>,
+    package_history: Vec<f64>,
+    package_history_timestamps: Vec<i64>,
+}
+
+impl Collector for RaplCollector {
+    fn name(&self) -> &'static str { "rapl" }
+
+    // Reads sysfs directly, no sysinfo refresh needed
+    fn collect(&mut self, ctx: &CollectContext<'_>, data: &mut SystemData) -> Result<()> {
+        let zones = read_rapl_zones(Path::new(POWERCAP_ROOT));
+        if zones.is_empty() {
+            self.reset_state();
+            data.cpu_power = None;
+            return Ok(());
+        }
+
+        let now_ms = ctx.timestamp.timestamp_millis();
+        let mut domains = Vec::with_capacity(zones.len());
+        for zone in zones {
+            let mut power_w = 0.0;
+            let state = self.domains.entry(zone.id.clone()).or_insert_with(|| DomainState {
+                energy_uj: zone.energy_uj,
+                at: ctx.now,
+                history: Vec::new(),
+                history_timestamps: Vec::new(),
+            });
+            let elapsed = ctx.now.duration_since(state.at).as_secs_f64();
+            if elapsed > 0.001 {
+                power_w = energy_delta_uj(state.energy_uj, zone.energy_uj, zone.max_energy_range_uj) as f64 / 1_000_000.0 / elapsed;
+                push_history(&mut state.history, power_w);
+                push_history(&mut state.history_timestamps, now_ms);
+            }
+            state.energy_uj = zone.energy_uj;
+            state.at = ctx.now;
+            domains.push(RaplDomain {
+                id: zone.id,
+                name: zone.name,
+                energy_uj: zone.energy_uj,
+                power_w,
+                history: state.history.clone(),
+                history_timestamps: state.history_timestamps.clone(),
+            });
+        }
+        // Forget zones that disappeared (driver unloaded)
+        self.domains.retain(|id, _| domains.iter().any(|d| &d.id == id));
+
+        // Sub-domains (core, uncore, dram) are part of their package; psys covers the whole platform
+        let package_power_w = domains.iter().filter(|d| d.name.starts_with("package") && !d.name.contains('/')).map(|d| d.power_w).sum();
+        // The first cycle has no deltas yet
+        if domains.iter().any(|d| !d.history.is_empty()) {
+            push_history(&mut self.package_history, package_power_w);
+            push_history(&mut self.package_history_timestamps, now_ms);
+        }
+
+        data.cpu_power = Some(CpuPowerData {
+            domains,
+            package_power_w,
+            package_history: self.package_history.clone(),
+            package_history_timestamps: self.package_history_timestamps.clone(),
+        });
+        Ok(())
+    }
+
+    fn reset_state(&mut self) {
+        self.domains.clear();
+        self.package_history.clear();
+        self.package_history_timestamps.clear();
+    }
+}
+
+/// Energy used between two readings of a counter that wraps at `max_range` (max_energy_range_uj).
+pub fn energy_delta_uj(previous: u64, current: u64, max_range: u64) -> u64 {
+    if current >= previous {
+        current - previous
+    } else {
+        // Wrapped (once, at any sane sampling interval) back to zero after reaching max_range
+        max_range.saturating_sub(previous) + current
+    }
+}
+
+// --- powercap sysfs ---
+
+pub struct RaplZone {
+    pub id: String,
+    pub name: String,
+    pub energy_uj: u64,
+    pub max_energy_range_uj: u64,
+}
+
+fn read_trimmed(path: &Path) -> Option<String> {
+    fs::read_to_string(path).ok().map(|s| s.trim().to_string()).filter(|s| !s.is_empty())
+}
+
+/// Every readable RAPL zone under `root`, parents before their sub-zones. The MMIO interface
+/// (intel-rapl-mmio) duplicates the package counters and is skipped.
+pub fn read_rapl_zones(root: &Path) -> Vec<RaplZone> {
+    let Ok(entries) = fs::read_dir(root) else { return Vec::new() };
+    let mut ids: Vec<String> = entries
+        .flatten()
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        // "intel-rapl" itself is the control type, zones are "intel-rapl:N" and "intel-rapl:N:M"
+        .filter(|id| id.starts_with("intel-rapl:"))
+        .collect();
+    // "intel-rapl:0" < "intel-rapl:0:0" < "intel-rapl:1"
+    ids.sort_by_key(|id| id.split(':').skip(1).map(|n| n.parse::<u32>().unwrap_or(0)).collect::<Vec<_>>());
+
+    let names: HashMap<String, String> = ids.iter().filter_map(|id| Some((id.clone(), read_trimmed(&root.join(id).join("name"))?))).collect();
+    ids.iter()
+        .filter_map(|id| {
+            let dir = root.join(id);
+            // Root-only since the PLATYPUS mitigations; unreadable zones are skipped
+            let energy_uj = read_trimmed(&dir.join("energy_uj"))?.parse().ok()?;
+            // Without the range a wrap can't be told apart from a huge delta
+            let max_energy_range_uj = read_trimmed(&dir.join("max_energy_range_uj"))?.parse().ok()?;
+            let own_name = names.get(id).cloned().unwrap_or_else(|| id.clone());
+            // Sub-zones are named after their package: "package-0/core"
+            let name = match id.rsplit_once(':').filter(|(parent, _)| parent.contains(':')) {
+                Some((parent, _)) => format!("{}/{}", names.get(parent).map(String::as_str).unwrap_or(parent), own_name),
+                None => own_name,
+            };
+            Some(RaplZone { id: id.clone(), name, energy_uj, max_energy_range_uj })
+        })
+        .collect()
+}
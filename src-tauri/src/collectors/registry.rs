@@ -0,0 +1,209 @@
+// TauriViteReact/src-tauri/src/collectors/registry.rs
+// Collector trait + registry: each subsystem owns its state (histories, previous
+// counters), declares what sysinfo data it needs, and writes its part of SystemData.
+
+use crate::models::SystemData;
+use crate::utils::error::Result;
+use chrono::{DateTime, Local};
+use serde::Serialize;
+use std::time::{Duration, Instant};
+use sysinfo::{CpuRefreshKind, ProcessRefreshKind, System, SystemExt};
+
+/// The sysinfo refreshes a collector depends on. The registry refreshes the union
+/// of what enabled collectors need, once per cycle, before any of them run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct RefreshRequirements {
+    pub cpu: bool,
+    pub memory: bool,
+    pub processes: bool,
+    pub networks: bool,
+    pub disks: bool,
+}
+
+impl RefreshRequirements {
+    pub const NONE: Self = RefreshRequirements { cpu: false, memory: false, processes: false, networks: false, disks: false };
+
+    pub fn union(self, other: Self) -> Self {
+        RefreshRequirements {
+            cpu: self.cpu || other.cpu,
+            memory: self.memory || other.memory,
+            processes: self.processes || other.processes,
+            networks: self.networks || other.networks,
+            disks: self.disks || other.disks,
+        }
+    }
+
+    /// Refreshes exactly the requested parts of `sys`.
+    pub fn apply(&self, sys: &mut System) {
+        // Process CPU% is computed against the global CPU delta, so processes imply cpu
+        if self.cpu || self.processes {
+            sys.refresh_cpu_specifics(CpuRefreshKind::everything());
+        }
+        if self.processes {
+            // Disk usage enabled for the per-process / system-wide I/O figures
+            sys.refresh_processes_specifics(ProcessRefreshKind::everything().with_disk_usage());
+        }
+        if self.memory {
+            sys.refresh_memory();
+        }
+        if self.networks {
+            sys.refresh_networks_list();
+            sys.refresh_networks();
+        }
+        if self.disks {
+            sys.refresh_disks_list();
+            sys.refresh_disks();
+        }
+        log::trace!("Sysinfo refreshed: {:?}", self);
+    }
+}
+
+/// Shared, read-only inputs for one collection cycle.
+pub struct CollectContext<'a> {
+    pub sys: &'a System,
+    pub now: Instant,            // Monotonic time for rate deltas
+    pub timestamp: DateTime<Local>, // Wall-clock time of the snapshot
+}
+
+pub trait Collector: Send {
+    /// Stable identifier used by commands and logs (e.g. "cpu", "disk").
+    fn name(&self) -> &'static str;
+
+    /// sysinfo data that must be fresh before `collect` runs.
+    fn refresh_requirements(&self) -> RefreshRequirements {
+        RefreshRequirements::NONE
+    }
+
+    /// Updates the collector's own state and writes its fields into `data`.
+    /// Collectors run in registry order, so later ones may read what earlier ones wrote.
+    fn collect(&mut self, ctx: &CollectContext<'_>, data: &mut SystemData) -> Result<()>;
+
+    /// Drops accumulated state (histories, previous counters), e.g. when disabled.
+    fn reset_state(&mut self);
+}
+
+struct CollectorEntry {
+    collector: Box<dyn Collector>,
+    enabled: bool,
+    last_duration: Option<Duration>,
+    last_error: Option<String>,
+}
+
+/// Status of one registered collector, as returned by the `list_collectors` command.
+#[derive(Serialize, Clone, Debug)]
+pub struct CollectorStatus {
+    pub name: String,
+    pub enabled: bool,
+    pub order: usize,
+    pub refresh: RefreshRequirements,
+    pub last_duration_ms: Option<f64>,
+    pub last_error: Option<String>,
+}
+
+#[derive(Default)]
+pub struct CollectorRegistry {
+    entries: Vec<CollectorEntry>,
+}
+
+impl std::fmt::Debug for CollectorRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.entries.iter().map(|e| (e.collector.name(), e.enabled))).finish()
+    }
+}
+
+impl CollectorRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registry with the built-in collectors in their default order.
+    pub fn with_builtin() -> Self {
+        let mut registry = CollectorRegistry::new();
+        for collector in super::builtin_collectors() {
+            registry.register(collector);
+        }
+        registry
+    }
+
+    /// Appends a collector (enabled). A collector with the same name is replaced in place.
+    pub fn register(&mut self, collector: Box<dyn Collector>) {
+        let entry = CollectorEntry { collector, enabled: true, last_duration: None, last_error: None };
+        match self.entries.iter().position(|e| e.collector.name() == entry.collector.name()) {
+            Some(index) => self.entries[index] = entry,
+            None => self.entries.push(entry),
+        }
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.entries.iter().any(|e| e.collector.name() == name)
+    }
+
+    /// Enables or disables a collector. Disabling also resets its state, so re-enabling
+    /// starts with fresh histories instead of a gap. Returns false for unknown names.
+    pub fn set_enabled(&mut self, name: &str, enabled: bool) -> bool {
+        let Some(entry) = self.entries.iter_mut().find(|e| e.collector.name() == name) else {
+            return false;
+        };
+        if entry.enabled && !enabled {
+            entry.collector.reset_state();
+            entry.last_duration = None;
+            entry.last_error = None;
+        }
+        entry.enabled = enabled;
+        log::info!("Collector '{}' {}", name, if enabled { "enabled" } else { "disabled" });
+        true
+    }
+
+    /// Reorders collectors. Names not listed keep their relative order after the listed ones.
+    pub fn set_order(&mut self, names: &[String]) -> std::result::Result<(), String> {
+        if let Some(unknown) = names.iter().find(|n| !self.contains(n)) {
+            return Err(format!("Unknown collector '{}'", unknown));
+        }
+        let rank = |name: &str| names.iter().position(|n| n == name).unwrap_or(usize::MAX);
+        // Stable sort keeps unlisted collectors in their existing order
+        self.entries.sort_by_key(|e| rank(e.collector.name()));
+        Ok(())
+    }
+
+    /// Union of the refresh requirements of all enabled collectors.
+    pub fn refresh_requirements(&self) -> RefreshRequirements {
+        self.entries
+            .iter()
+            .filter(|e| e.enabled)
+            .fold(RefreshRequirements::NONE, |acc, e| acc.union(e.collector.refresh_requirements()))
+    }
+
+    /// Runs every enabled collector in order, timing each one. A failing collector is
+    /// logged and recorded in its status; the others still run.
+    pub fn collect_all(&mut self, ctx: &CollectContext<'_>, data: &mut SystemData) {
+        for entry in self.entries.iter_mut().filter(|e| e.enabled) {
+            let started = Instant::now();
+            let result = entry.collector.collect(ctx, data);
+            let elapsed = started.elapsed();
+            entry.last_duration = Some(elapsed);
+            match result {
+                Ok(()) => entry.last_error = None,
+                Err(e) => {
+                    log::warn!("Collector '{}' failed: {}", entry.collector.name(), e);
+                    entry.last_error = Some(e.to_string());
+                }
+            }
+            log::trace!("Collector '{}' took {:.2}ms", entry.collector.name(), elapsed.as_secs_f64() * 1000.0);
+        }
+    }
+
+    pub fn statuses(&self) -> Vec<CollectorStatus> {
+        self.entries
+            .iter()
+            .enumerate()
+            .map(|(order, e)| CollectorStatus {
+                name: e.collector.name().to_string(),
+                enabled: e.enabled,
+                order,
+                refresh: e.collector.refresh_requirements(),
+                last_duration_ms: e.last_duration.map(|d| d.as_secs_f64() * 1000.0),
+                last_error: e.last_error.clone(),
+            })
+            .collect()
+    }
+}
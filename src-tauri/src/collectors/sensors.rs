@@ -0,0 +1,195 @@
+// TauriViteReact/src-tauri/src/collectors/sensors.rs
+// Hardware sensors from sysfs: every hwmon chip (CPU, motherboard, NVMe, ...) with its
+// temperature, fan, voltage and current inputs, plus the kernel's thermal zones.
+
+use crate::collectors::registry::{CollectContext, Collector};
+use crate::models::{SensorChip, SensorData, SensorKind, SensorReading, SystemData};
+use crate::utils::error::Result;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const HWMON_ROOT: &str = "/sys/class/hwmon";
+pub const THERMAL_ROOT: &str = "/sys/class/thermal";
+
+/// Sensor readings plus a per-sensor history and its sample times, keyed by sensor id
+#[derive(Default)]
+pub struct SensorsCollector {
+    histories: HashMap<String, (Vec<f64>, Vec<i64>)>,
+}
+
+impl Collector for SensorsCollector {
+    fn name(&self) -> &'static str { "sensors" }
+
+    // Reads sysfs directly, no sysinfo refresh needed
+    fn collect(&mut self, ctx: &CollectContext<'_>, data: &mut SystemData) -> Result<()> {
+        let mut sensors = SensorData {
+            chips: read_hwmon_chips(Path::new(HWMON_ROOT)),
+            thermal_zones: read_thermal_zones(Path::new(THERMAL_ROOT)),
+        };
+
+        let mut seen = HashSet::new();
+        let readings = sensors.chips.iter_mut().flat_map(|chip| chip.sensors.iter_mut()).chain(sensors.thermal_zones.iter_mut());
+        for reading in readings {
+            let (history, timestamps) = self.histories.entry(reading.id.clone()).or_default();
+            super::push_history(history, reading.value);
+            super::push_history(timestamps, ctx.timestamp.timestamp_millis());
+            reading.history = history.clone();
+            reading.history_timestamps = timestamps.clone();
+            seen.insert(reading.id.clone());
+        }
+        // Forget sensors that went away (hot-unplugged drive, unloaded driver)
+        self.histories.retain(|id, _| seen.contains(id));
+
+        data.sensors = sensors;
+        Ok(())
+    }
+
+    fn reset_state(&mut self) { self.histories.clear(); }
+}
+
+// --- sysfs helpers ---
+
+fn read_trimmed(path: &Path) -> Option<String> {
+    fs::read_to_string(path).ok().map(|s| s.trim().to_string()).filter(|s| !s.is_empty())
+}
+
+// Some drivers return EIO/ENODATA for inputs that exist but are not wired up; those read as None
+fn read_number(path: &Path) -> Option<f64> {
+    read_trimmed(path)?.parse::<f64>().ok()
+}
+
+// Entries of `root` starting with `prefix`, ordered by their numeric suffix (hwmon2 < hwmon10)
+fn numbered_dirs(root: &Path, prefix: &str) -> Vec<(String, PathBuf)> {
+    let Ok(entries) = fs::read_dir(root) else { return Vec::new() };
+    let mut dirs: Vec<(u32, String, PathBuf)> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            let index = name.strip_prefix(prefix)?.parse::<u32>().ok()?;
+            Some((index, name, entry.path()))
+        })
+        .collect();
+    dirs.sort();
+    dirs.into_iter().map(|(_, name, path)| (name, path)).collect()
+}
+
+// --- hwmon ---
+
+/// Reads every chip under `root` (normally /sys/class/hwmon). Chips without readable inputs are skipped.
+pub fn read_hwmon_chips(root: &Path) -> Vec<SensorChip> {
+    let mut chips = Vec::new();
+    let mut used_ids = HashSet::new();
+    for (hwmon_name, dir) in numbered_dirs(root, "hwmon") {
+        let name = read_trimmed(&dir.join("name")).unwrap_or_else(|| hwmon_name.clone());
+        // The owning device name survives reboots, unlike the hwmonN numbering
+        let mut id = fs::canonicalize(dir.join("device"))
+            .ok()
+            .and_then(|device| device.file_name().map(|n| n.to_string_lossy().into_owned()))
+            .unwrap_or_else(|| hwmon_name.clone());
+        if !used_ids.insert(id.clone()) {
+            id = format!("{}:{}", id, hwmon_name); // Several hwmon chips on one device
+            used_ids.insert(id.clone());
+        }
+        let sensors = read_chip_inputs(&dir, &id);
+        if sensors.is_empty() {
+            log::trace!("hwmon chip {} ({}) has no readable inputs", hwmon_name, name);
+            continue;
+        }
+        chips.push(SensorChip { id, name, sensors });
+    }
+    chips
+}
+
+// Maps an input base name like "temp1" / "in0" / "fan2" / "curr1" to its kind
+fn input_kind(base: &str) -> Option<(SensorKind, u32)> {
+    let (kind, index) = if let Some(rest) = base.strip_prefix("temp") {
+        (SensorKind::Temperature, rest)
+    } else if let Some(rest) = base.strip_prefix("fan") {
+        (SensorKind::Fan, rest)
+    } else if let Some(rest) = base.strip_prefix("curr") {
+        (SensorKind::Current, rest)
+    } else if let Some(rest) = base.strip_prefix("in") {
+        (SensorKind::Voltage, rest)
+    } else {
+        return None;
+    };
+    Some((kind, index.parse().ok()?))
+}
+
+fn read_chip_inputs(dir: &Path, chip_id: &str) -> Vec<SensorReading> {
+    let Ok(entries) = fs::read_dir(dir) else { return Vec::new() };
+    let mut inputs: Vec<(SensorKind, u32, String)> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let file_name = entry.file_name().to_string_lossy().into_owned();
+            let base = file_name.strip_suffix("_input")?.to_string();
+            let (kind, index) = input_kind(&base)?;
+            Some((kind, index, base))
+        })
+        .collect();
+    inputs.sort();
+
+    inputs
+        .into_iter()
+        .filter_map(|(kind, _, base)| {
+            if read_trimmed(&dir.join(format!("{}_enable", base))).as_deref() == Some("0") {
+                return None;
+            }
+            // sysfs units: millidegrees, RPM, millivolts, milliamps
+            let scale = match kind {
+                SensorKind::Fan => 1.0,
+                SensorKind::Temperature | SensorKind::Voltage | SensorKind::Current => 1000.0,
+            };
+            let attribute = |suffix: &str| read_number(&dir.join(format!("{}_{}", base, suffix))).map(|v| v / scale);
+            let value = attribute("input")?;
+            Some(SensorReading {
+                id: format!("{}/{}", chip_id, base),
+                kind,
+                label: read_trimmed(&dir.join(format!("{}_label", base))).unwrap_or_else(|| base.clone()),
+                value,
+                min: attribute("min"),
+                max: attribute("max"),
+                crit: attribute("crit"),
+                history: Vec::new(),
+                history_timestamps: Vec::new(),
+            })
+        })
+        .collect()
+}
+
+// --- Thermal zones ---
+
+/// Reads every thermal zone under `root` (normally /sys/class/thermal). `crit` comes from the
+/// "critical" trip point and `max` from the "hot" one, where the platform defines them.
+pub fn read_thermal_zones(root: &Path) -> Vec<SensorReading> {
+    numbered_dirs(root, "thermal_zone")
+        .into_iter()
+        .filter_map(|(zone_name, dir)| {
+            let value = read_number(&dir.join("temp"))? / 1000.0;
+            let mut max: Option<f64> = None;
+            let mut crit: Option<f64> = None;
+            for trip in 0.. {
+                let Some(trip_type) = read_trimmed(&dir.join(format!("trip_point_{}_type", trip))) else { break };
+                let Some(temp) = read_number(&dir.join(format!("trip_point_{}_temp", trip))).map(|t| t / 1000.0) else { continue };
+                let slot = match trip_type.as_str() {
+                    "critical" => &mut crit,
+                    "hot" => &mut max,
+                    _ => continue,
+                };
+                *slot = Some(slot.map_or(temp, |existing| existing.min(temp)));
+            }
+            Some(SensorReading {
+                label: read_trimmed(&dir.join("type")).unwrap_or_else(|| zone_name.clone()),
+                id: zone_name,
+                kind: SensorKind::Temperature,
+                value,
+                min: None,
+                max,
+                crit,
+                history: Vec::new(),
+                history_timestamps: Vec::new(),
+            })
+        })
+        .collect()
+}
@@ -69,6 +69,7 @@ pub fn collect_windows_gpu_data(
                 memory_total: memory_total_gb,
                 power_usage: 0.0, // WMI doesn't provide this easily
                 utilization_history: Vec::new(),
+                history_timestamps: Vec::new(), // Stamped by GpuCollector
             });
         }
     }
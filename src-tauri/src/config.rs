@@ -0,0 +1,106 @@
+// TauriViteReact/src-tauri/src/config.rs
+// User-editable settings, read from config.json in the app config directory.
+
+use crate::alerts::{default_rules, AlertRule};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+pub const CONFIG_FILE_NAME: &str = "config.json";
+
+/// Top-level configuration. Every section falls back to its defaults,
+/// so a missing or partial config.json is fine.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct AppConfig {
+    pub metrics: MetricsConfig,
+    pub history: HistoryConfig,
+    pub alerts: AlertsConfig,
+}
+
+/// Settings for the Prometheus `/metrics` endpoint
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct MetricsConfig {
+    pub enabled: bool,
+    pub listen_address: String, // Localhost only unless explicitly changed
+}
+
+impl Default for MetricsConfig {
+    fn default() -> Self {
+        MetricsConfig {
+            enabled: false,
+            listen_address: "127.0.0.1:9184".to_string(),
+        }
+    }
+}
+
+/// Settings for the on-disk metric history database
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct HistoryConfig {
+    pub enabled: bool,
+    pub retention_days: u32, // 0 keeps samples forever
+}
+
+impl Default for HistoryConfig {
+    fn default() -> Self {
+        HistoryConfig {
+            enabled: true,
+            retention_days: 7,
+        }
+    }
+}
+
+/// Threshold alert rules; edits made through the alert commands are written back here
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct AlertsConfig {
+    pub enabled: bool,
+    pub rules: Vec<AlertRule>,
+}
+
+impl Default for AlertsConfig {
+    fn default() -> Self {
+        AlertsConfig {
+            enabled: true,
+            rules: default_rules(),
+        }
+    }
+}
+
+impl AppConfig {
+    /// Loads the config from `path`, falling back to defaults if it is missing or invalid.
+    pub fn load(path: &Path) -> Self {
+        match std::fs::read_to_string(path) {
+            Ok(content) => match serde_json::from_str(&content) {
+                Ok(config) => {
+                    log::info!("Loaded config from {}", path.display());
+                    config
+                }
+                Err(e) => {
+                    log::warn!("Invalid config file {}: {}. Using defaults.", path.display(), e);
+                    AppConfig::default()
+                }
+            },
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                log::debug!("No config file at {}, using defaults.", path.display());
+                AppConfig::default()
+            }
+            Err(e) => {
+                log::warn!("Failed to read config file {}: {}. Using defaults.", path.display(), e);
+                AppConfig::default()
+            }
+        }
+    }
+
+    /// Writes the config to `path` as pretty-printed JSON, creating the directory if needed.
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let content = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
+        std::fs::write(path, content)?;
+        log::info!("Saved config to {}", path.display());
+        Ok(())
+    }
+}
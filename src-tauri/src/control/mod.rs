@@ -0,0 +1,6 @@
+// TauriViteReact/src-tauri/src/control/mod.rs
+// Actions that change system state (as opposed to collectors, which only observe it).
+
+pub mod process;
+
+pub use process::{ExpectedProcess, ProcessActionOutcome, ProcessActionResult};
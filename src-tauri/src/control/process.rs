@@ -0,0 +1,385 @@
+// TauriViteReact/src-tauri/src/control/process.rs
+// Sending signals to processes: terminate with escalation, arbitrary signals,
+// whole trees and process groups. Every action reports a structured outcome.
+
+use serde::Serialize;
+use std::collections::HashMap;
+use std::time::Duration;
+use sysinfo::{Pid, PidExt, ProcessExt, ProcessStatus, System, SystemExt};
+
+pub const DEFAULT_TERMINATE_TIMEOUT_MS: u64 = 3000;
+const KILL_WAIT: Duration = Duration::from_millis(2000);
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+// Re-enumeration passes while freezing a tree; a fork bomb could otherwise keep it going forever
+#[cfg(unix)]
+const MAX_FREEZE_ROUNDS: usize = 10;
+
+/// What happened to a process we tried to signal.
+#[derive(Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum ProcessActionOutcome {
+    Signaled,     // Signal delivered (no waiting involved)
+    Exited,       // Exited after SIGTERM within the timeout
+    Killed,       // Ignored SIGTERM, exited after escalation to SIGKILL
+    StillRunning, // Survived SIGKILL too (e.g. stuck in uninterruptible I/O)
+    PermissionDenied,
+    NoSuchProcess,
+    PidReused { actual_name: String }, // PID now belongs to a different process
+    InvalidSignal { signal: String },
+    Unsupported { message: String },
+    Failed { message: String },
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct ProcessActionResult {
+    pub pid: u32,
+    pub signal: String,
+    pub outcome: ProcessActionOutcome,
+}
+
+/// What the caller believes the PID refers to. Any field that is set must still match,
+/// otherwise the action is refused with `PidReused`.
+#[derive(Debug, Clone, Default)]
+pub struct ExpectedProcess {
+    pub name: Option<String>,
+    pub start_time: Option<u64>, // Seconds since epoch, as reported by sysinfo
+}
+
+// --- Signal numbers / names ---
+
+#[cfg(unix)]
+const SIGNALS: &[(&str, i32)] = &[
+    ("HUP", libc::SIGHUP),
+    ("INT", libc::SIGINT),
+    ("QUIT", libc::SIGQUIT),
+    ("ABRT", libc::SIGABRT),
+    ("KILL", libc::SIGKILL),
+    ("USR1", libc::SIGUSR1),
+    ("USR2", libc::SIGUSR2),
+    ("PIPE", libc::SIGPIPE),
+    ("ALRM", libc::SIGALRM),
+    ("TERM", libc::SIGTERM),
+    ("CHLD", libc::SIGCHLD),
+    ("CONT", libc::SIGCONT),
+    ("STOP", libc::SIGSTOP),
+    ("TSTP", libc::SIGTSTP),
+    ("TTIN", libc::SIGTTIN),
+    ("TTOU", libc::SIGTTOU),
+    ("WINCH", libc::SIGWINCH),
+];
+
+// Only termination is meaningful where there are no signals; both map to TerminateProcess
+#[cfg(not(unix))]
+const SIGNALS: &[(&str, i32)] = &[("TERM", 15), ("KILL", 9)];
+
+fn signal_number(name: &str) -> Option<i32> {
+    let trimmed = name.trim();
+    if let Ok(number) = trimmed.parse::<i32>() {
+        // Unnamed signals (e.g. real-time ones) are allowed on unix; the kernel validates them
+        let known = SIGNALS.iter().any(|(_, n)| *n == number);
+        return (known || (cfg!(unix) && (1..=64).contains(&number))).then_some(number);
+    }
+    let upper = trimmed.to_ascii_uppercase();
+    let short = upper.strip_prefix("SIG").unwrap_or(&upper);
+    SIGNALS.iter().find(|(n, _)| *n == short).map(|(_, n)| *n)
+}
+
+fn signal_name(signal: i32) -> String {
+    SIGNALS
+        .iter()
+        .find(|(_, n)| *n == signal)
+        .map(|(name, _)| format!("SIG{}", name))
+        .unwrap_or_else(|| signal.to_string())
+}
+
+fn sigterm() -> i32 { signal_number("TERM").unwrap_or(15) }
+fn sigkill() -> i32 { signal_number("KILL").unwrap_or(9) }
+
+// --- Low-level delivery ---
+
+#[cfg(unix)]
+fn deliver(pid: u32, signal: i32, _sys: &System) -> Result<(), ProcessActionOutcome> {
+    // SAFETY: kill(2) has no memory-safety preconditions; pid was range-checked by the caller
+    if unsafe { libc::kill(pid as libc::pid_t, signal) } == 0 {
+        return Ok(());
+    }
+    Err(errno_outcome(std::io::Error::last_os_error(), signal))
+}
+
+#[cfg(not(unix))]
+fn deliver(pid: u32, _signal: i32, sys: &System) -> Result<(), ProcessActionOutcome> {
+    match sys.process(Pid::from_u32(pid)) {
+        Some(process) if process.kill() => Ok(()),
+        Some(_) => Err(ProcessActionOutcome::Failed { message: "TerminateProcess failed".to_string() }),
+        None => Err(ProcessActionOutcome::NoSuchProcess),
+    }
+}
+
+#[cfg(unix)]
+fn errno_outcome(err: std::io::Error, signal: i32) -> ProcessActionOutcome {
+    match err.raw_os_error() {
+        Some(libc::ESRCH) => ProcessActionOutcome::NoSuchProcess,
+        Some(libc::EPERM) => ProcessActionOutcome::PermissionDenied,
+        Some(libc::EINVAL) => ProcessActionOutcome::InvalidSignal { signal: signal.to_string() },
+        _ => ProcessActionOutcome::Failed { message: err.to_string() },
+    }
+}
+
+// --- Identity checks ---
+
+// Rejects PIDs that would address more than one process (0, or > i32::MAX which
+// becomes negative as pid_t) and the monitor's own process.
+fn validate_pid(pid: u32) -> Result<(), ProcessActionOutcome> {
+    if pid == 0 || pid > i32::MAX as u32 {
+        return Err(ProcessActionOutcome::Failed { message: format!("Invalid PID {}", pid) });
+    }
+    if pid == std::process::id() {
+        return Err(ProcessActionOutcome::Failed { message: "Refusing to signal the monitor itself".to_string() });
+    }
+    Ok(())
+}
+
+// Refreshes `pid` and checks it is still the process the caller meant
+fn verify_identity(sys: &mut System, pid: u32, expected: &ExpectedProcess) -> Result<(), ProcessActionOutcome> {
+    validate_pid(pid)?;
+    let sys_pid = Pid::from_u32(pid);
+    if !sys.refresh_process(sys_pid) {
+        return Err(ProcessActionOutcome::NoSuchProcess);
+    }
+    let Some(process) = sys.process(sys_pid) else {
+        return Err(ProcessActionOutcome::NoSuchProcess);
+    };
+    let name_matches = expected.name.as_deref().is_none_or(|name| name == process.name());
+    let start_matches = expected.start_time.is_none_or(|start| start == process.start_time());
+    if !name_matches || !start_matches {
+        return Err(ProcessActionOutcome::PidReused { actual_name: process.name().to_string() });
+    }
+    Ok(())
+}
+
+// Alive = still present, not a zombie, and still the same process (same start time)
+fn is_alive(sys: &mut System, pid: u32, start_time: u64) -> bool {
+    let sys_pid = Pid::from_u32(pid);
+    if !sys.refresh_process(sys_pid) {
+        return false;
+    }
+    match sys.process(sys_pid) {
+        Some(process) => process.status() != ProcessStatus::Zombie && process.start_time() == start_time,
+        None => false,
+    }
+}
+
+async fn wait_for_exit(sys: &mut System, pid: u32, start_time: u64, timeout: Duration) -> bool {
+    let deadline = tokio::time::Instant::now() + timeout;
+    loop {
+        if !is_alive(sys, pid, start_time) {
+            return true;
+        }
+        if tokio::time::Instant::now() >= deadline {
+            return false;
+        }
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
+
+fn result(pid: u32, signal: i32, outcome: ProcessActionOutcome) -> ProcessActionResult {
+    ProcessActionResult { pid, signal: signal_name(signal), outcome }
+}
+
+// --- Public actions ---
+
+/// Sends SIGTERM and waits up to `timeout`; escalates to SIGKILL if the process is still alive.
+pub async fn terminate(pid: u32, expected: &ExpectedProcess, timeout: Duration) -> ProcessActionResult {
+    let mut sys = System::new();
+    if let Err(outcome) = verify_identity(&mut sys, pid, expected) {
+        return result(pid, sigterm(), outcome);
+    }
+    let start_time = sys.process(Pid::from_u32(pid)).map(|p| p.start_time()).unwrap_or_default();
+
+    if let Err(outcome) = deliver(pid, sigterm(), &sys) {
+        return result(pid, sigterm(), outcome);
+    }
+    log::info!("Sent SIGTERM to {}, waiting up to {:?}", pid, timeout);
+    if wait_for_exit(&mut sys, pid, start_time, timeout).await {
+        return result(pid, sigterm(), ProcessActionOutcome::Exited);
+    }
+
+    log::info!("Process {} survived SIGTERM, escalating to SIGKILL", pid);
+    // Re-check identity right before escalating, the PID could have been recycled
+    if !is_alive(&mut sys, pid, start_time) {
+        return result(pid, sigterm(), ProcessActionOutcome::Exited);
+    }
+    if let Err(outcome) = deliver(pid, sigkill(), &sys) {
+        return result(pid, sigkill(), outcome);
+    }
+    let outcome = if wait_for_exit(&mut sys, pid, start_time, KILL_WAIT).await {
+        ProcessActionOutcome::Killed
+    } else {
+        ProcessActionOutcome::StillRunning
+    };
+    result(pid, sigkill(), outcome)
+}
+
+/// Sends an arbitrary signal (name like "HUP"/"SIGUSR1" or a number) without waiting.
+pub fn send_signal(pid: u32, signal: &str, expected: &ExpectedProcess) -> ProcessActionResult {
+    let Some(signal_num) = signal_number(signal) else {
+        return ProcessActionResult {
+            pid,
+            signal: signal.to_string(),
+            outcome: ProcessActionOutcome::InvalidSignal { signal: signal.to_string() },
+        };
+    };
+    let mut sys = System::new();
+    if let Err(outcome) = verify_identity(&mut sys, pid, expected) {
+        return result(pid, signal_num, outcome);
+    }
+    let outcome = match deliver(pid, signal_num, &sys) {
+        Ok(()) => ProcessActionOutcome::Signaled,
+        Err(outcome) => outcome,
+    };
+    log::info!("Signal {} to {}: {:?}", signal_name(signal_num), pid, outcome);
+    result(pid, signal_num, outcome)
+}
+
+// Pre-order walk of `root` and its descendants in the current process table
+fn process_tree(sys: &System, root: u32) -> Vec<u32> {
+    let mut children: HashMap<u32, Vec<u32>> = HashMap::new();
+    for (child_pid, process) in sys.processes() {
+        if let Some(parent) = process.parent() {
+            children.entry(parent.as_u32()).or_default().push(child_pid.as_u32());
+        }
+    }
+    for kids in children.values_mut() {
+        kids.sort_unstable();
+    }
+    let own_pid = std::process::id();
+    let mut tree = Vec::new();
+    let mut stack = vec![root];
+    while let Some(current) = stack.pop() {
+        // Never include the monitor itself, even if it was launched from this tree
+        if current == own_pid || tree.contains(&current) {
+            continue;
+        }
+        tree.push(current);
+        if let Some(kids) = children.get(&current) {
+            stack.extend(kids.iter().rev());
+        }
+    }
+    tree
+}
+
+// SIGSTOPs every member of the tree, re-enumerating until a pass finds no new members.
+// Returns the frozen tree and the pids this call stopped; members that were already
+// stopped or traced (T/t) are left out of the latter so they stay stopped afterwards.
+#[cfg(unix)]
+fn freeze_tree(sys: &mut System, root: u32) -> (Vec<u32>, Vec<u32>) {
+    let mut tree = process_tree(sys, root);
+    let mut seen: Vec<u32> = Vec::new();
+    let mut stopped = Vec::new();
+    for _ in 0..MAX_FREEZE_ROUNDS {
+        let new_members: Vec<u32> = tree.iter().copied().filter(|member| !seen.contains(member)).collect();
+        if new_members.is_empty() {
+            break;
+        }
+        for member in new_members {
+            seen.push(member);
+            let already_stopped = sys
+                .process(Pid::from_u32(member))
+                .is_some_and(|process| matches!(process.status(), ProcessStatus::Stop | ProcessStatus::Tracing));
+            if !already_stopped && deliver(member, libc::SIGSTOP, sys).is_ok() {
+                stopped.push(member);
+            }
+        }
+        // Children forked before their parent was stopped show up on the next pass
+        sys.refresh_processes();
+        tree = process_tree(sys, root);
+    }
+    (tree, stopped)
+}
+
+/// Signals `pid` and all of its descendants. On unix the tree is frozen first: members are
+/// SIGSTOPped and the tree re-enumerated until no new children appear, so nothing forked
+/// mid-way escapes. Only the processes this call stopped are resumed with SIGCONT afterwards.
+/// Returns one result per process, root first.
+pub fn signal_tree(pid: u32, signal: &str, expected: &ExpectedProcess) -> Vec<ProcessActionResult> {
+    let Some(signal_num) = signal_number(signal) else {
+        return vec![ProcessActionResult {
+            pid,
+            signal: signal.to_string(),
+            outcome: ProcessActionOutcome::InvalidSignal { signal: signal.to_string() },
+        }];
+    };
+    let mut sys = System::new();
+    if let Err(outcome) = verify_identity(&mut sys, pid, expected) {
+        return vec![result(pid, signal_num, outcome)];
+    }
+    sys.refresh_processes();
+
+    #[cfg(unix)]
+    let (tree, stopped) = if signal_num != libc::SIGSTOP && signal_num != libc::SIGCONT {
+        freeze_tree(&mut sys, pid)
+    } else {
+        (process_tree(&sys, pid), Vec::new())
+    };
+    #[cfg(not(unix))]
+    let tree = process_tree(&sys, pid);
+
+    let results: Vec<ProcessActionResult> = tree
+        .iter()
+        .map(|&member| {
+            let outcome = match deliver(member, signal_num, &sys) {
+                Ok(()) => ProcessActionOutcome::Signaled,
+                Err(outcome) => outcome,
+            };
+            result(member, signal_num, outcome)
+        })
+        .collect();
+
+    // Resume so catchable signals (e.g. SIGTERM) actually get handled
+    #[cfg(unix)]
+    for &member in &stopped {
+        let _ = deliver(member, libc::SIGCONT, &sys);
+    }
+
+    log::info!("Signal {} to process tree of {} ({} processes)", signal_name(signal_num), pid, tree.len());
+    results
+}
+
+/// Signals every process in process group `pgid`.
+#[cfg(unix)]
+pub fn signal_group(pgid: u32, signal: &str) -> ProcessActionResult {
+    let Some(signal_num) = signal_number(signal) else {
+        return ProcessActionResult {
+            pid: pgid,
+            signal: signal.to_string(),
+            outcome: ProcessActionOutcome::InvalidSignal { signal: signal.to_string() },
+        };
+    };
+    if let Err(outcome) = validate_pid(pgid) {
+        return result(pgid, signal_num, outcome);
+    }
+    // SAFETY: getpgrp/killpg have no memory-safety preconditions
+    if pgid == unsafe { libc::getpgrp() } as u32 {
+        return result(
+            pgid,
+            signal_num,
+            ProcessActionOutcome::Failed { message: "Refusing to signal the monitor's own process group".to_string() },
+        );
+    }
+    let outcome = if unsafe { libc::killpg(pgid as libc::pid_t, signal_num) } == 0 {
+        ProcessActionOutcome::Signaled
+    } else {
+        errno_outcome(std::io::Error::last_os_error(), signal_num)
+    };
+    log::info!("Signal {} to process group {}: {:?}", signal_name(signal_num), pgid, outcome);
+    result(pgid, signal_num, outcome)
+}
+
+#[cfg(not(unix))]
+pub fn signal_group(pgid: u32, signal: &str) -> ProcessActionResult {
+    ProcessActionResult {
+        pid: pgid,
+        signal: signal.to_string(),
+        outcome: ProcessActionOutcome::Unsupported { message: "Process groups are only supported on unix".to_string() },
+    }
+}
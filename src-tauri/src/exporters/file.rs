@@ -0,0 +1,309 @@
+// TauriViteReact/src-tauri/src/exporters/file.rs
+// CSV and JSON exports of a snapshot or of one in-memory history series, for pasting into
+// incident reports and spreadsheets. Shared by the Tauri commands and the CLI.
+
+use crate::models::SystemData;
+use crate::storage;
+use crate::utils::error::{MonitorError, Result};
+use chrono::{Local, TimeZone};
+use serde::ser::{SerializeMap, Serializer};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::Write;
+use std::str::FromStr;
+
+const TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.3f"; // Local time; parsed as a date by spreadsheets
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    Csv,
+    Json,
+}
+
+impl ExportFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+        }
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "csv" => Ok(ExportFormat::Csv),
+            "json" => Ok(ExportFormat::Json),
+            other => Err(format!("Unknown export format '{}' (expected csv or json)", other)),
+        }
+    }
+}
+
+/// An exportable history series, for pickers and `--list-series`.
+#[derive(Serialize, Clone, Debug)]
+pub struct ExportSeriesInfo {
+    pub id: String,           // e.g. "cpu_history", "network:eth0", "gpu:0"
+    pub title: String,
+    pub columns: Vec<String>, // Headers with units, after the time columns
+    pub samples: usize,
+}
+
+/// One history series as rows: a timestamp per sample and one column per value.
+pub struct HistoryTable {
+    pub id: String,
+    pub title: String,
+    pub timestamps: Vec<i64>, // Epoch ms, oldest first
+    pub columns: Vec<HistoryColumn>,
+}
+
+pub struct HistoryColumn {
+    pub header: String,          // Name and unit, e.g. "rx (KB/s)"
+    pub values: Vec<Option<f64>>, // Aligned with the table's timestamps; None where the series has no sample
+}
+
+// --- History series ---
+
+/// Every history series present in `data`.
+pub fn list_history_series(data: &SystemData) -> Vec<ExportSeriesInfo> {
+    series_ids(data)
+        .into_iter()
+        .filter_map(|id| history_table(data, &id).ok())
+        .map(|table| ExportSeriesInfo {
+            samples: table.timestamps.len(),
+            columns: table.columns.into_iter().map(|column| column.header).collect(),
+            id: table.id,
+            title: table.title,
+        })
+        .collect()
+}
+
+fn series_ids(data: &SystemData) -> Vec<String> {
+    let mut ids: Vec<String> = vec!["cpu_history".into(), "memory_history".into(), "system_disk_history".into()];
+    let mut interfaces: Vec<&String> = data.network_data.keys().collect();
+    interfaces.sort();
+    ids.extend(interfaces.into_iter().map(|name| format!("network:{}", name)));
+    let mut disks: Vec<&String> = data.disk_data.keys().collect();
+    disks.sort();
+    ids.extend(disks.into_iter().map(|name| format!("disk:{}", name)));
+    ids.extend((0..data.gpu_data.len()).map(|index| format!("gpu:{}", index)));
+    if data.cpu_power.is_some() {
+        ids.push("cpu_power".into());
+    }
+    if let Some(power) = &data.power {
+        ids.extend(power.batteries.iter().map(|battery| format!("battery:{}", battery.name)));
+    }
+    if data.pressure.is_some() {
+        ids.push("pressure".into());
+    }
+    ids
+}
+
+// (header, history, epoch ms of its samples)
+type SourceColumn<'a> = (String, &'a [f64], &'a [i64]);
+
+/// The series `id` (see `list_history_series`), one row per sample time of any of its columns.
+pub fn history_table(data: &SystemData, id: &str) -> Result<HistoryTable> {
+    let unknown = || MonitorError::Other(format!("Unknown history series '{}'", id));
+    // Per-device series are "<kind>:<key>"; the rest are plain names
+    let (kind, key) = id.split_once(':').unwrap_or((id, ""));
+    let plain = !id.contains(':');
+    let (title, columns): (String, Vec<SourceColumn>) = match kind {
+        "cpu_history" if plain => (
+            "CPU usage per core".into(),
+            data.cpu_history
+                .iter()
+                .enumerate()
+                .map(|(core, history)| (format!("core {} (%)", core), history.as_slice(), data.cpu_history_timestamps.as_slice()))
+                .collect(),
+        ),
+        "memory_history" if plain => ("Memory usage".into(), vec![("used (%)".into(), data.memory_history.as_slice(), data.memory_history_timestamps.as_slice())]),
+        "system_disk_history" if plain => (
+            "System disk I/O".into(),
+            vec![
+                ("read (KB/s)".into(), data.system_disk_read_history.as_slice(), data.system_disk_history_timestamps.as_slice()),
+                ("write (KB/s)".into(), data.system_disk_write_history.as_slice(), data.system_disk_history_timestamps.as_slice()),
+            ],
+        ),
+        "network" if !plain => {
+            let net = data.network_data.get(key).ok_or_else(unknown)?;
+            let times = net.history_timestamps.as_slice();
+            (
+                format!("Network {}", key),
+                vec![
+                    ("rx (KB/s)".into(), net.rx_history.as_slice(), times),
+                    ("tx (KB/s)".into(), net.tx_history.as_slice(), times),
+                    ("errors (/s)".into(), net.error_history.as_slice(), times),
+                    ("drops (/s)".into(), net.drop_history.as_slice(), times),
+                ],
+            )
+        }
+        "disk" if !plain => {
+            let disk = data.disk_data.get(key).ok_or_else(unknown)?;
+            let times = disk.history_timestamps.as_slice();
+            (
+                format!("Disk {} ({})", key, disk.mount_point),
+                vec![
+                    ("read (KB/s)".into(), disk.read_history.as_slice(), times),
+                    ("write (KB/s)".into(), disk.write_history.as_slice(), times),
+                ],
+            )
+        }
+        "gpu" if !plain => {
+            let gpu = key.parse::<usize>().ok().and_then(|index| data.gpu_data.get(index)).ok_or_else(unknown)?;
+            (format!("GPU {}", gpu.name), vec![("utilization (%)".into(), gpu.utilization_history.as_slice(), gpu.history_timestamps.as_slice())])
+        }
+        "cpu_power" if plain => {
+            let cpu_power = data.cpu_power.as_ref().ok_or_else(unknown)?;
+            let mut columns = vec![("package (W)".to_string(), cpu_power.package_history.as_slice(), cpu_power.package_history_timestamps.as_slice())];
+            columns.extend(cpu_power.domains.iter().map(|domain| (format!("{} (W)", domain.name), domain.history.as_slice(), domain.history_timestamps.as_slice())));
+            ("CPU power (RAPL)".into(), columns)
+        }
+        "battery" if !plain => {
+            let battery = data.power.as_ref().and_then(|power| power.batteries.iter().find(|b| b.name == key)).ok_or_else(unknown)?;
+            let times = battery.history_timestamps.as_slice();
+            (
+                format!("Battery {}", key),
+                vec![
+                    ("charge (%)".into(), battery.capacity_history.as_slice(), times),
+                    ("power (W)".into(), battery.power_history.as_slice(), times),
+                ],
+            )
+        }
+        "pressure" if plain => {
+            let pressure = data.pressure.as_ref().ok_or_else(unknown)?;
+            let mut columns = Vec::new();
+            for (resource, stat) in pressure.resources() {
+                let Some(stat) = stat else { continue };
+                columns.push((format!("{} some (%)", resource), stat.some.history.as_slice(), stat.some.history_timestamps.as_slice()));
+                if let Some(full) = &stat.full {
+                    columns.push((format!("{} full (%)", resource), full.history.as_slice(), full.history_timestamps.as_slice()));
+                }
+            }
+            ("Pressure stall".into(), columns)
+        }
+        _ => return Err(unknown()),
+    };
+
+    // A history and its sample times end together; older values without a time are start-up
+    // padding. Columns are merged on their times, leaving gaps where one has no sample.
+    let columns: Vec<(String, HashMap<i64, f64>)> = columns
+        .into_iter()
+        .map(|(header, history, times)| {
+            let samples = history.len().min(times.len());
+            let times = &times[times.len() - samples..];
+            let history = &history[history.len() - samples..];
+            (header, times.iter().copied().zip(history.iter().copied()).collect())
+        })
+        .collect();
+    let mut timestamps: Vec<i64> = columns.iter().flat_map(|(_, values)| values.keys().copied()).collect();
+    timestamps.sort_unstable();
+    timestamps.dedup();
+    let columns = columns
+        .into_iter()
+        .map(|(header, values)| HistoryColumn { header, values: timestamps.iter().map(|ts| values.get(ts).copied()).collect() })
+        .collect();
+    Ok(HistoryTable { id: id.to_string(), title, timestamps, columns })
+}
+
+pub fn write_history(out: &mut impl Write, table: &HistoryTable, format: ExportFormat) -> Result<()> {
+    match format {
+        ExportFormat::Csv => {
+            let mut header = vec!["time (local)".to_string(), "epoch_ms".to_string()];
+            header.extend(table.columns.iter().map(|column| column.header.clone()));
+            write_csv_row(out, &header)?;
+            for (row, timestamp) in table.timestamps.iter().enumerate() {
+                let mut fields = vec![format_time(*timestamp), timestamp.to_string()];
+                fields.extend(table.columns.iter().map(|column| column.values[row].map(|v| v.to_string()).unwrap_or_default()));
+                write_csv_row(out, &fields)?;
+            }
+        }
+        ExportFormat::Json => {
+            let samples = (0..table.timestamps.len()).map(|row| HistoryRow { table, row }).collect();
+            write_json(out, &HistoryDocument { series: &table.id, title: &table.title, samples })?;
+        }
+    }
+    Ok(())
+}
+
+// Serialized directly rather than through serde_json::Value, whose maps sort their keys
+#[derive(Serialize)]
+struct HistoryDocument<'a> {
+    series: &'a str,
+    title: &'a str,
+    samples: Vec<HistoryRow<'a>>,
+}
+
+// One JSON sample, keyed by the same headers as the CSV columns
+struct HistoryRow<'a> {
+    table: &'a HistoryTable,
+    row: usize,
+}
+
+impl Serialize for HistoryRow<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let timestamp = self.table.timestamps[self.row];
+        let mut map = serializer.serialize_map(Some(self.table.columns.len() + 2))?;
+        map.serialize_entry("time", &format_time(timestamp))?;
+        map.serialize_entry("epoch_ms", &timestamp)?;
+        for column in &self.table.columns {
+            map.serialize_entry(&column.header, &column.values[self.row])?;
+        }
+        map.end()
+    }
+}
+
+// --- Snapshot ---
+
+/// The whole snapshot: as-is for JSON, one `metric,label,value,unit` row per sample for CSV
+/// (the same samples as the persistent history and alert rules).
+pub fn write_snapshot(out: &mut impl Write, data: &SystemData, format: ExportFormat) -> Result<()> {
+    match format {
+        ExportFormat::Csv => {
+            let time = data.timestamp.format(TIME_FORMAT).to_string();
+            write_csv_row(out, &["time (local)", "metric", "label", "value", "unit"].map(String::from))?;
+            for (metric, label, value) in storage::flatten_samples(data) {
+                let unit = storage::metric(metric).map_or("", |m| m.unit);
+                write_csv_row(out, &[time.clone(), metric.to_string(), label, value.to_string(), unit.to_string()])?;
+            }
+        }
+        ExportFormat::Json => write_json(out, data)?,
+    }
+    Ok(())
+}
+
+/// Default file name for an export, e.g. "system-monitor-network-eth0-20250101-120000.csv".
+pub fn export_file_name(what: &str, format: ExportFormat) -> String {
+    let what: String = what.chars().map(|c| if c.is_ascii_alphanumeric() || c == '_' { c } else { '-' }).collect();
+    format!("system-monitor-{}-{}.{}", what.trim_matches('-'), Local::now().format("%Y%m%d-%H%M%S"), format.extension())
+}
+
+// --- Writers ---
+
+fn format_time(epoch_ms: i64) -> String {
+    Local.timestamp_millis_opt(epoch_ms).single().map(|time| time.format(TIME_FORMAT).to_string()).unwrap_or_default()
+}
+
+// RFC 4180: quote fields containing separators, quotes or line breaks
+fn write_csv_row(out: &mut impl Write, fields: &[String]) -> Result<()> {
+    let line: Vec<String> = fields
+        .iter()
+        .map(|field| {
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.clone()
+            }
+        })
+        .collect();
+    writeln!(out, "{}", line.join(","))?;
+    Ok(())
+}
+
+fn write_json<T: Serialize>(out: &mut impl Write, value: &T) -> Result<()> {
+    serde_json::to_writer_pretty(&mut *out, value).map_err(|e| MonitorError::Other(e.to_string()))?;
+    writeln!(out)?;
+    Ok(())
+}
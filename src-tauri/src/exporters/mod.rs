@@ -0,0 +1,5 @@
+// TauriViteReact/src-tauri/src/exporters/mod.rs
+
+// Ways of getting SystemData out of the app besides the Tauri frontend
+pub mod file;
+pub mod prometheus;
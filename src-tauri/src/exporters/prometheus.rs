@@ -0,0 +1,234 @@
+// TauriViteReact/src-tauri/src/exporters/prometheus.rs
+// Prometheus text-format (v0.0.4) rendering of SystemData plus a minimal /metrics listener.
+
+use crate::models::{DiskData, GpuData, SystemData};
+use std::fmt::Write as _;
+use std::sync::{Arc, RwLock};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+const METRIC_PREFIX: &str = "sysmon";
+const BYTES_PER_GIB: f64 = 1024.0 * 1024.0 * 1024.0;
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+// Limits for reading a scrape request, so a slow or oversized client can't hold a task open
+const REQUEST_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+const MAX_REQUEST_BYTES: usize = 8192;
+
+// Extracts one sample value from a disk/GPU entry
+type ValueFn<T> = fn(&T) -> f64;
+
+/// Latest rendered exposition, shared between the collection loop and the HTTP listener.
+#[derive(Clone, Default)]
+pub struct MetricsHandle(Arc<RwLock<String>>);
+
+impl MetricsHandle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Re-renders the exposition from a freshly collected snapshot.
+    pub fn update(&self, data: &SystemData) {
+        let rendered = render_metrics(data);
+        match self.0.write() {
+            Ok(mut guard) => *guard = rendered,
+            Err(poisoned) => log::error!("Metrics lock poisoned: {}", poisoned),
+        }
+    }
+
+    fn current(&self) -> String {
+        self.0.read().map(|guard| guard.clone()).unwrap_or_default()
+    }
+}
+
+// One metric family: HELP/TYPE header followed by its labelled samples
+struct Family<'a> {
+    out: &'a mut String,
+    name: String,
+}
+
+impl<'a> Family<'a> {
+    fn gauge(out: &'a mut String, name: &str, help: &str) -> Self {
+        let name = format!("{}_{}", METRIC_PREFIX, name);
+        let _ = writeln!(out, "# HELP {} {}", name, help);
+        let _ = writeln!(out, "# TYPE {} gauge", name);
+        Family { out, name }
+    }
+
+    fn sample(&mut self, labels: &[(&str, &str)], value: f64) {
+        self.out.push_str(&self.name);
+        if !labels.is_empty() {
+            self.out.push('{');
+            for (i, (key, val)) in labels.iter().enumerate() {
+                if i > 0 {
+                    self.out.push(',');
+                }
+                let _ = write!(self.out, "{}=\"{}\"", key, escape_label_value(val));
+            }
+            self.out.push('}');
+        }
+        let _ = writeln!(self.out, " {}", format_value(value));
+    }
+}
+
+fn escape_label_value(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+fn format_value(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value.is_infinite() {
+        if value > 0.0 { "+Inf".to_string() } else { "-Inf".to_string() }
+    } else {
+        value.to_string()
+    }
+}
+
+pub fn render_metrics(data: &SystemData) -> String {
+    let mut out = String::with_capacity(8 * 1024);
+
+    // --- CPU ---
+    {
+        let mut family = Family::gauge(&mut out, "cpu_usage_percent", "CPU usage per logical core in percent.");
+        for (core, usage) in data.cpu_usage.iter().enumerate() {
+            let core = core.to_string();
+            family.sample(&[("core", &core)], *usage);
+        }
+    }
+
+    // --- Memory ---
+    Family::gauge(&mut out, "memory_used_bytes", "Used physical memory in bytes.")
+        .sample(&[], data.memory_used * BYTES_PER_GIB);
+    Family::gauge(&mut out, "memory_total_bytes", "Total physical memory in bytes.")
+        .sample(&[], data.memory_total * BYTES_PER_GIB);
+
+    // --- Network (sorted so scrapes are stable) ---
+    let mut interfaces: Vec<_> = data.network_data.iter().collect();
+    interfaces.sort_by(|a, b| a.0.cmp(b.0));
+    {
+        let mut family = Family::gauge(&mut out, "network_receive_bytes_per_second", "Receive rate per network interface.");
+        for (name, net) in &interfaces {
+            family.sample(&[("interface", name)], net.current_rx_speed * 1024.0);
+        }
+    }
+    {
+        let mut family = Family::gauge(&mut out, "network_transmit_bytes_per_second", "Transmit rate per network interface.");
+        for (name, net) in &interfaces {
+            family.sample(&[("interface", name)], net.current_tx_speed * 1024.0);
+        }
+    }
+
+    // --- Disks ---
+    let mut disks: Vec<_> = data.disk_data.values().collect();
+    disks.sort_by(|a, b| a.mount_point.cmp(&b.mount_point));
+    let disk_families: [(&str, &str, ValueFn<DiskData>); 5] = [
+        ("disk_total_bytes", "Filesystem size in bytes.", |d| d.total_space * BYTES_PER_GIB),
+        ("disk_used_bytes", "Filesystem space used in bytes.", |d| d.used_space * BYTES_PER_GIB),
+        ("disk_used_percent", "Filesystem space used in percent.", |d| d.used_percentage),
+        ("disk_read_bytes_per_second", "Read rate of the device backing the filesystem.", |d| d.read_kb_per_sec * 1024.0),
+        ("disk_write_bytes_per_second", "Write rate of the device backing the filesystem.", |d| d.write_kb_per_sec * 1024.0),
+    ];
+    for (name, help, value_of) in disk_families {
+        let mut family = Family::gauge(&mut out, name, help);
+        for disk in &disks {
+            family.sample(
+                &[("device", &disk.name), ("mountpoint", &disk.mount_point), ("type", &disk.disk_type)],
+                value_of(disk),
+            );
+        }
+    }
+    Family::gauge(&mut out, "system_disk_read_bytes_per_second", "System-wide disk read rate summed over processes.")
+        .sample(&[], data.system_disk_read_per_sec * 1024.0);
+    Family::gauge(&mut out, "system_disk_write_bytes_per_second", "System-wide disk write rate summed over processes.")
+        .sample(&[], data.system_disk_write_per_sec * 1024.0);
+
+    // --- GPUs (index label keeps identical model names apart) ---
+    let gpu_families: [(&str, &str, ValueFn<GpuData>); 5] = [
+        ("gpu_utilization_percent", "GPU utilization in percent.", |g| g.utilization),
+        ("gpu_temperature_celsius", "GPU temperature in degrees Celsius.", |g| g.temperature),
+        ("gpu_memory_used_bytes", "GPU memory used in bytes.", |g| g.memory_used * BYTES_PER_GIB),
+        ("gpu_memory_total_bytes", "GPU memory total in bytes.", |g| g.memory_total * BYTES_PER_GIB),
+        ("gpu_power_watts", "GPU power draw in watts.", |g| g.power_usage),
+    ];
+    for (name, help, value_of) in gpu_families {
+        let mut family = Family::gauge(&mut out, name, help);
+        for (index, gpu) in data.gpu_data.iter().enumerate() {
+            let index = index.to_string();
+            family.sample(&[("gpu", &index), ("name", &gpu.name)], value_of(gpu));
+        }
+    }
+
+    Family::gauge(&mut out, "last_sample_timestamp_seconds", "Unix time of the snapshot these metrics come from.")
+        .sample(&[], data.timestamp.timestamp_millis() as f64 / 1000.0);
+
+    out
+}
+
+/// Serves `GET /metrics` on `listen_address` until the listener fails.
+pub async fn serve(listen_address: String, metrics: MetricsHandle) -> std::io::Result<()> {
+    let listener = TcpListener::bind(&listen_address).await?;
+    log::info!("Prometheus metrics available at http://{}/metrics", listen_address);
+
+    loop {
+        let (mut stream, peer) = match listener.accept().await {
+            Ok(conn) => conn,
+            Err(e) => {
+                log::warn!("Failed to accept metrics connection: {}", e);
+                continue;
+            }
+        };
+        let metrics = metrics.clone();
+        tokio::spawn(async move {
+            let request = match tokio::time::timeout(REQUEST_TIMEOUT, read_request_head(&mut stream)).await {
+                Ok(Ok(head)) => head,
+                Ok(Err(e)) => {
+                    log::debug!("Failed to read metrics request from {}: {}", peer, e);
+                    return;
+                }
+                Err(_) => {
+                    log::debug!("Timed out reading metrics request from {}", peer);
+                    return;
+                }
+            };
+            let request = String::from_utf8_lossy(&request);
+            let mut parts = request.lines().next().unwrap_or("").split_whitespace();
+            let method = parts.next().unwrap_or("");
+            let path = parts.next().unwrap_or("");
+
+            let (status, content_type, body) = match (method, path.split('?').next().unwrap_or("")) {
+                ("GET", "/metrics") => ("200 OK", CONTENT_TYPE, metrics.current()),
+                ("GET", _) => ("404 Not Found", "text/plain; charset=utf-8", "Not Found\n".to_string()),
+                _ => ("405 Method Not Allowed", "text/plain; charset=utf-8", "Method Not Allowed\n".to_string()),
+            };
+            let response = format!(
+                "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                content_type,
+                body.len(),
+                body
+            );
+            if let Err(e) = stream.write_all(response.as_bytes()).await {
+                log::debug!("Failed to write metrics response to {}: {}", peer, e);
+            }
+            let _ = stream.shutdown().await;
+        });
+    }
+}
+
+// Reads until the blank line ending the request headers; only the request line is used,
+// but the whole head has to be consumed before responding
+async fn read_request_head(stream: &mut tokio::net::TcpStream) -> std::io::Result<Vec<u8>> {
+    let mut head = Vec::new();
+    let mut buf = [0u8; 1024];
+    while !head.windows(4).any(|window| window == b"\r\n\r\n") {
+        if head.len() >= MAX_REQUEST_BYTES {
+            return Err(std::io::Error::new(std::io::ErrorKind::InvalidData, "request headers too large"));
+        }
+        let read = stream.read(&mut buf).await?;
+        if read == 0 {
+            return Err(std::io::ErrorKind::UnexpectedEof.into());
+        }
+        head.extend_from_slice(&buf[..read]);
+    }
+    Ok(head)
+}
@@ -1,17 +1,32 @@
 // TauriViteReact/src-tauri/src/lib.rs (Corrected v8 - Add system-wide Disk I/O)
 
+pub mod alerts;
 pub mod collectors;
+pub mod config;
+pub mod control;
+pub mod exporters;
 pub mod models;
+pub mod storage;
 pub mod utils;
 
-use models::{AppStateInner, DiskData, GpuData, NetworkData, SystemData, HISTORY_LENGTH}; // Added AppStateInner, HISTORY_LENGTH
-use std::{collections::HashMap, time::Instant}; // Added Instant
-use std::sync::Mutex;
+use alerts::{Alert, AlertEngine, AlertEvent, AlertRule};
+use exporters::file::{ExportFormat, ExportSeriesInfo};
+use exporters::prometheus::MetricsHandle;
+use collectors::CollectorStatus;
+use collectors::connections::NetworkConnection;
+use collectors::processes::{ProcessDetails, ProcessInfo, ProcessPage, ProcessQuery, ProcessTreeNode};
+use control::{ExpectedProcess, ProcessActionResult};
+use models::{AppStateInner, SystemData};
+use storage::{HistorySeries, HistorySeriesInfo, HistoryStore, RecordingStatus, ReplayStatus, SessionInfo, SessionReader, SessionRecorder, SessionStatus};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use sysinfo::{PidExt, SystemExt};
 use tauri::{AppHandle, Emitter, Manager, State, Wry};
-// Corrected: Removed unused System and DiskUsage imports from this top level
-use sysinfo::{CpuExt, CpuRefreshKind, PidExt, ProcessExt, ProcessRefreshKind, SystemExt}; // Added ProcessExt, kept SystemExt
 
-const UPDATE_INTERVAL_MS: u64 = 1000;
+// Sampling interval bounds; the interval itself can be changed at runtime via set_update_interval
+pub const DEFAULT_UPDATE_INTERVAL_MS: u64 = 1000;
+pub const MIN_UPDATE_INTERVAL_MS: u64 = 250;
+pub const MAX_UPDATE_INTERVAL_MS: u64 = 60_000;
 
 // --- AppState definition using AppStateInner ---
 pub struct AppState(Mutex<AppStateInner>);
@@ -19,22 +34,55 @@ impl Default for AppState { // Default for the wrapper
     fn default() -> Self { AppState(Mutex::new(AppStateInner::default())) }
 }
 
-// --- Define helper structs outside functions ---
-#[derive(Debug)]
-struct InitialData {
-    cpu_usage: Vec<f64>,
-    memory_used: f64,
-    memory_total: f64,
-    mem_percent_collected: f64,
-    process_data_raw: Vec<(sysinfo::Pid, String, f32, u64)>,
+// --- On-disk history (None if disabled in config or the database failed to open) ---
+pub struct HistoryState(Mutex<Option<HistoryStore>>);
+
+// --- Threshold alerts evaluated against every snapshot ---
+pub struct AlertState(Mutex<AlertEngine>);
+
+// --- Latest live snapshot, kept for exports (None until the first collection) ---
+#[derive(Default)]
+pub struct LatestSnapshot(Mutex<Option<SystemData>>);
+
+// --- Session recording and replay; while a replay runs, live snapshots are not emitted ---
+#[derive(Default)]
+pub struct SessionControl {
+    recorder: Option<SessionRecorder>,
+    replay: Option<ReplayHandle>,
 }
-#[derive(Debug)]
-struct ComplexData {
-    network_data: HashMap<String, NetworkData>,
-    disk_data: HashMap<String, DiskData>,
-    gpu_data: Vec<GpuData>,
+pub struct SessionState(Mutex<SessionControl>);
+
+// Shared with the replay thread
+struct ReplayHandle {
+    path: String,
+    total: u64,
+    speed: f64,
+    position: Arc<AtomicU64>,
+    stop: Arc<AtomicBool>,
 }
 
+impl SessionControl {
+    fn status(&self) -> SessionStatus {
+        SessionStatus {
+            recording: self.recorder.as_ref().map(|recorder| RecordingStatus {
+                path: recorder.path().to_string_lossy().into_owned(),
+                frames: recorder.frames(),
+            }),
+            replay: self.replay.as_ref().map(|replay| ReplayStatus {
+                path: replay.path.clone(),
+                position: replay.position.load(Ordering::Relaxed),
+                total: replay.total,
+                speed: replay.speed,
+            }),
+        }
+    }
+}
+
+// --- Requested sampling interval (ms); the monitoring loop watches for changes ---
+pub struct SamplingInterval(tokio::sync::watch::Sender<u64>);
+impl Default for SamplingInterval {
+    fn default() -> Self { SamplingInterval(tokio::sync::watch::Sender::new(DEFAULT_UPDATE_INTERVAL_MS)) }
+}
 
 #[tauri::command]
 fn get_platform() -> Result<String, String> { /* ... same as before ... */ Ok(utils::get_platform_name()) }
@@ -61,184 +109,409 @@ fn get_current_system_data(state: State<'_, AppState>) -> Result<SystemData, Str
      }
 }
 
+#[tauri::command]
+fn query_metric_history(
+    state: State<'_, HistoryState>,
+    metric: String,
+    label: Option<String>,
+    start_ms: i64,
+    end_ms: i64,
+    max_points: Option<usize>,
+) -> Result<Vec<HistorySeries>, String> {
+    log::debug!("Executing query_metric_history: {} [{:?}] {}..{}", metric, label, start_ms, end_ms);
+    let guard = state.0.lock().map_err(|e| format!("History mutex poisoned: {}", e))?;
+    let store = guard.as_ref().ok_or_else(|| "Persistent history is disabled".to_string())?;
+    store
+        .query(&metric, label.as_deref(), start_ms, end_ms, max_points)
+        .map_err(|e| format!("Failed to query history: {}", e))
+}
 
-// --- Helper Function Definitions ---
-
-// Phase 1: Refresh Sysinfo
-fn refresh_sysinfo(state: &mut AppStateInner) {
-    state.sys.refresh_networks_list();
-    state.sys.refresh_disks_list();
-    state.sys.refresh_cpu(); // Needed for usage calculation delta
-    // Refresh processes with disk usage enabled
-    state.sys.refresh_processes_specifics(ProcessRefreshKind::everything().with_disk_usage());
-    state.sys.refresh_cpu_specifics(CpuRefreshKind::everything());
-    state.sys.refresh_memory();
-    // state.sys.refresh_processes(); // Already refreshed with specifics above
-    state.sys.refresh_networks();
-    state.sys.refresh_disks();
-    log::trace!("Sysinfo refreshed (incl. process disk usage)");
-}
-
-// Phase 2: Collect initial data
-fn collect_initial_data(
-    state: &AppStateInner,
-) -> Result<InitialData, Box<dyn std::error::Error + Send + Sync>> { /* ... same as before ... */
-    let cpu_usage = collectors::cpu::collect_cpu_usage(&state.sys)?;
-    let (memory_used, memory_total, mem_percent_collected) =
-        collectors::memory::collect_memory_info(&state.sys)?;
-    let process_data_raw = collectors::cpu::collect_process_info(&state.sys)?;
-    Ok(InitialData {
-        cpu_usage,
-        memory_used,
-        memory_total,
-        mem_percent_collected,
-        process_data_raw,
-    })
-}
-
-// Phase 3: Update basic histories
-fn update_basic_histories(
-    initial_data: &InitialData,
-    app_state: &mut AppStateInner,
-) -> Result<(), Box<dyn std::error::Error + Send + Sync>> { /* ... same as before ... */
-    collectors::cpu::update_cpu_history(&initial_data.cpu_usage, &mut app_state.cpu_history)?;
-    collectors::memory::update_memory_history(
-        initial_data.mem_percent_collected,
-        &mut app_state.memory_history,
-    )?;
-    Ok(())
-}
-
-// Phase 4: Collect complex data
-fn collect_complex_data(
-    app_state: &mut AppStateInner,
-    initial_data: &InitialData,
-    now_instant: std::time::Instant,
-) -> Result<ComplexData, Box<dyn std::error::Error + Send + Sync>> { /* ... same as before ... */
-    collectors::network::update_network_data(&app_state.sys, &mut app_state.network_state, now_instant)?;
-    collectors::disk::collect_disk_data(&app_state.sys, &mut app_state.disk_state, now_instant)?;
-    let gpu_data_vec = collectors::gpu::collect_gpu_data_entry(&app_state.sys, &initial_data.cpu_usage, &mut app_state.gpu_utilization_history)?;
-    Ok(ComplexData { network_data: app_state.network_state.clone(), disk_data: app_state.disk_state.clone(), gpu_data: gpu_data_vec })
-}
-
-// --- NEW Phase: Collect and Update System-Wide Disk I/O ---
-// Returns (read_kb_per_sec, write_kb_per_sec)
-fn collect_and_update_system_disk_io(
-    state: &mut AppStateInner,
-    now_instant: Instant,
-) -> (f64, f64) {
-    let mut total_read_delta: u64 = 0;
-    let mut total_write_delta: u64 = 0;
-
-    // Iterate through processes and sum disk usage deltas
-    for process in state.sys.processes().values() {
-        let usage = process.disk_usage();
-        total_read_delta += usage.read_bytes;
-        total_write_delta += usage.written_bytes;
+#[tauri::command]
+fn list_history_series(state: State<'_, HistoryState>) -> Result<Vec<HistorySeriesInfo>, String> {
+    let guard = state.0.lock().map_err(|e| format!("History mutex poisoned: {}", e))?;
+    let store = guard.as_ref().ok_or_else(|| "Persistent history is disabled".to_string())?;
+    store.list_series().map_err(|e| format!("Failed to list history series: {}", e))
+}
+
+#[tauri::command]
+fn list_collectors(state: State<'_, AppState>) -> Result<Vec<CollectorStatus>, String> {
+    let guard = state.0.lock().map_err(|e| format!("Mutex poisoned in command: {}", e))?;
+    Ok(guard.collectors.statuses())
+}
+
+#[tauri::command]
+fn set_collector_enabled(state: State<'_, AppState>, name: String, enabled: bool) -> Result<Vec<CollectorStatus>, String> {
+    let mut guard = state.0.lock().map_err(|e| format!("Mutex poisoned in command: {}", e))?;
+    if !guard.collectors.set_enabled(&name, enabled) {
+        return Err(format!("Unknown collector '{}'", name));
     }
+    Ok(guard.collectors.statuses())
+}
 
-    let mut read_kb_per_sec = 0.0;
-    let mut write_kb_per_sec = 0.0;
+#[tauri::command]
+fn set_collector_order(state: State<'_, AppState>, names: Vec<String>) -> Result<Vec<CollectorStatus>, String> {
+    let mut guard = state.0.lock().map_err(|e| format!("Mutex poisoned in command: {}", e))?;
+    guard.collectors.set_order(&names)?;
+    Ok(guard.collectors.statuses())
+}
 
-    if let Some(last_time) = state.last_update_instant {
-        let delta_time = now_instant.duration_since(last_time).as_secs_f64();
-        if delta_time > 0.001 { // Avoid division by zero or tiny intervals
-            read_kb_per_sec = (total_read_delta as f64 / delta_time) / 1024.0;
-            write_kb_per_sec = (total_write_delta as f64 / delta_time) / 1024.0;
+#[tauri::command]
+fn get_update_interval(interval: State<'_, SamplingInterval>) -> u64 {
+    *interval.0.borrow()
+}
 
-            // Update read history
-            if state.system_disk_read_history.len() >= HISTORY_LENGTH {
-                state.system_disk_read_history.remove(0);
-            }
-            state.system_disk_read_history.push(read_kb_per_sec);
+#[tauri::command]
+fn set_update_interval(interval: State<'_, SamplingInterval>, interval_ms: u64) -> Result<u64, String> {
+    if !(MIN_UPDATE_INTERVAL_MS..=MAX_UPDATE_INTERVAL_MS).contains(&interval_ms) {
+        return Err(format!(
+            "Interval must be between {} and {} ms (got {})",
+            MIN_UPDATE_INTERVAL_MS, MAX_UPDATE_INTERVAL_MS, interval_ms
+        ));
+    }
+    log::info!("Sampling interval change requested: {}ms", interval_ms);
+    interval.0.send_replace(interval_ms);
+    Ok(interval_ms)
+}
 
-            // Update write history
-            if state.system_disk_write_history.len() >= HISTORY_LENGTH {
-                state.system_disk_write_history.remove(0);
-            }
-            state.system_disk_write_history.push(write_kb_per_sec);
 
-        } else {
-            // Delta time too small, reuse last history value if available
-             if let Some(&last_val) = state.system_disk_read_history.last() { if state.system_disk_read_history.len() >= HISTORY_LENGTH { state.system_disk_read_history.remove(0); } state.system_disk_read_history.push(last_val); } else if state.system_disk_read_history.is_empty() { state.system_disk_read_history.push(0.0); }
-             if let Some(&last_val) = state.system_disk_write_history.last() { if state.system_disk_write_history.len() >= HISTORY_LENGTH { state.system_disk_write_history.remove(0); } state.system_disk_write_history.push(last_val); } else if state.system_disk_write_history.is_empty() { state.system_disk_write_history.push(0.0); }
-        }
-    } else {
-        // First run, push 0 to history
-         if state.system_disk_read_history.is_empty() { state.system_disk_read_history.push(0.0); }
-         if state.system_disk_write_history.is_empty() { state.system_disk_write_history.push(0.0); }
+#[tauri::command]
+fn get_process_table(state: State<'_, AppState>, query: Option<ProcessQuery>) -> Result<ProcessPage, String> {
+    let mut guard = state.0.lock().map_err(|e| format!("Mutex poisoned in command: {}", e))?;
+    // Process data is as fresh as the last collection cycle; user names need the users list once
+    if guard.sys.users().is_empty() {
+        guard.sys.refresh_users_list();
+    }
+    Ok(collectors::processes::query_processes(&guard.sys, &query.unwrap_or_default()))
+}
+
+#[tauri::command]
+fn get_process_tree(state: State<'_, AppState>, collapsed: Option<Vec<u32>>) -> Result<Vec<ProcessTreeNode>, String> {
+    let guard = state.0.lock().map_err(|e| format!("Mutex poisoned in command: {}", e))?;
+    let collapsed = collapsed.unwrap_or_default().into_iter().collect();
+    Ok(collectors::processes::build_process_tree(&guard.sys, &collapsed))
+}
+
+#[tauri::command]
+fn get_process_details(state: State<'_, AppState>, pid: u32) -> Result<ProcessDetails, String> {
+    let mut guard = state.0.lock().map_err(|e| format!("Mutex poisoned in command: {}", e))?;
+    let sys_pid = sysinfo::Pid::from_u32(pid);
+    // Pick up processes started since the last cycle without touching the others
+    if guard.sys.process(sys_pid).is_none() && !guard.sys.refresh_process(sys_pid) {
+        return Err(format!("No process with PID {}", pid));
+    }
+    if guard.sys.users().is_empty() {
+        guard.sys.refresh_users_list();
+    }
+    collectors::processes::process_details(&guard.sys, pid).ok_or_else(|| format!("No process with PID {}", pid))
+}
+
+#[tauri::command]
+fn get_unit_processes(state: State<'_, AppState>, cgroup_path: String) -> Result<Vec<ProcessInfo>, String> {
+    let mut guard = state.0.lock().map_err(|e| format!("Mutex poisoned in command: {}", e))?;
+    if guard.sys.users().is_empty() {
+        guard.sys.refresh_users_list();
     }
+    Ok(collectors::processes::processes_in_cgroup(&guard.sys, &cgroup_path))
+}
+
+// --- Session recording / replay commands ---
+
+// Sessions live in <app data>/sessions
+fn sessions_dir(app: &AppHandle<Wry>) -> Result<std::path::PathBuf, String> {
+    app.path().app_data_dir().map(|dir| dir.join("sessions")).map_err(|e| format!("No app data dir: {}", e))
+}
+
+#[tauri::command]
+fn get_session_status(state: State<'_, SessionState>) -> Result<SessionStatus, String> {
+    let guard = state.0.lock().map_err(|e| format!("Mutex poisoned in command: {}", e))?;
+    Ok(guard.status())
+}
+
+#[tauri::command]
+fn list_sessions(app: AppHandle<Wry>) -> Result<Vec<SessionInfo>, String> {
+    Ok(storage::session::list_sessions(&sessions_dir(&app)?))
+}
+
+#[tauri::command]
+fn start_recording(app: AppHandle<Wry>, state: State<'_, SessionState>, interval: State<'_, SamplingInterval>) -> Result<SessionStatus, String> {
+    let mut guard = state.0.lock().map_err(|e| format!("Mutex poisoned in command: {}", e))?;
+    if guard.replay.is_some() {
+        return Err("Stop the replay before recording".to_string());
+    }
+    if guard.recorder.is_none() {
+        let name = format!("session-{}.{}", chrono::Local::now().format("%Y%m%d-%H%M%S"), storage::session::SESSION_EXTENSION);
+        let path = sessions_dir(&app)?.join(name);
+        let recorder = SessionRecorder::create(&path, &utils::get_platform_name(), *interval.0.borrow())
+            .map_err(|e| format!("Failed to create {}: {}", path.display(), e))?;
+        log::info!("Recording session to {}", path.display());
+        guard.recorder = Some(recorder);
+    }
+    Ok(guard.status())
+}
+
+#[tauri::command]
+fn stop_recording(state: State<'_, SessionState>) -> Result<SessionStatus, String> {
+    let mut guard = state.0.lock().map_err(|e| format!("Mutex poisoned in command: {}", e))?;
+    if let Some(recorder) = guard.recorder.take() {
+        log::info!("Recorded {} frames to {}", recorder.frames(), recorder.path().display());
+    }
+    Ok(guard.status())
+}
+
+#[tauri::command]
+fn start_replay(app: AppHandle<Wry>, state: State<'_, SessionState>, path: String, speed: Option<f64>) -> Result<SessionStatus, String> {
+    let speed = speed.unwrap_or(1.0);
+    if !(0.1..=100.0).contains(&speed) {
+        return Err(format!("Replay speed must be between 0.1 and 100, got {}", speed));
+    }
+    let mut guard = state.0.lock().map_err(|e| format!("Mutex poisoned in command: {}", e))?;
+    if guard.recorder.is_some() {
+        return Err("Stop recording before replaying".to_string());
+    }
+    if let Some(previous) = guard.replay.take() {
+        previous.stop.store(true, Ordering::Relaxed);
+    }
+    let reader = SessionReader::open(std::path::Path::new(&path)).map_err(|e| e.to_string())?;
+    let total = storage::session::count_frames(std::path::Path::new(&path)).map_err(|e| e.to_string())?;
+    let handle = ReplayHandle { path, total, speed, position: Arc::new(AtomicU64::new(0)), stop: Arc::new(AtomicBool::new(false)) };
+    let (position, stop) = (handle.position.clone(), handle.stop.clone());
+    log::info!("Replaying {} ({} frames) at {}x", handle.path, total, speed);
+    guard.replay = Some(handle);
+    std::thread::spawn(move || replay_session(app, reader, speed, position, stop));
+    Ok(guard.status())
+}
+
+#[tauri::command]
+fn stop_replay(state: State<'_, SessionState>) -> Result<SessionStatus, String> {
+    let mut guard = state.0.lock().map_err(|e| format!("Mutex poisoned in command: {}", e))?;
+    if let Some(replay) = guard.replay.take() {
+        replay.stop.store(true, Ordering::Relaxed);
+    }
+    Ok(guard.status())
+}
+
+// --- Export commands: the latest live snapshot or one of its histories, as CSV or JSON ---
+
+// An explicit path wins; otherwise a timestamped file in Downloads, or <app data>/exports without one
+fn export_path(app: &AppHandle<Wry>, path: Option<String>, what: &str, format: ExportFormat) -> Result<std::path::PathBuf, String> {
+    if let Some(path) = path {
+        return Ok(std::path::PathBuf::from(path));
+    }
+    let dir = match app.path().download_dir() {
+        Ok(dir) => dir,
+        Err(_) => app.path().app_data_dir().map(|dir| dir.join("exports")).map_err(|e| format!("No export directory: {}", e))?,
+    };
+    Ok(dir.join(exporters::file::export_file_name(what, format)))
+}
+
+fn write_export(path: &std::path::Path, write: impl FnOnce(&mut std::io::BufWriter<std::fs::File>) -> utils::error::Result<()>) -> Result<String, String> {
+    use std::io::Write;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    }
+    let file = std::fs::File::create(path).map_err(|e| format!("Failed to create {}: {}", path.display(), e))?;
+    let mut out = std::io::BufWriter::new(file);
+    write(&mut out).map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+    out.flush().map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+    log::info!("Exported {}", path.display());
+    Ok(path.to_string_lossy().into_owned())
+}
+
+#[tauri::command]
+fn list_export_series(latest: State<'_, LatestSnapshot>) -> Result<Vec<ExportSeriesInfo>, String> {
+    let guard = latest.0.lock().map_err(|e| format!("Mutex poisoned in command: {}", e))?;
+    Ok(guard.as_ref().map(exporters::file::list_history_series).unwrap_or_default())
+}
+
+// Returns the path written
+#[tauri::command]
+fn export_snapshot(app: AppHandle<Wry>, latest: State<'_, LatestSnapshot>, format: ExportFormat, path: Option<String>) -> Result<String, String> {
+    let guard = latest.0.lock().map_err(|e| format!("Mutex poisoned in command: {}", e))?;
+    let data = guard.as_ref().ok_or_else(|| "No data collected yet".to_string())?;
+    let path = export_path(&app, path, "snapshot", format)?;
+    write_export(&path, |out| exporters::file::write_snapshot(out, data, format))
+}
 
-    // Update the last update time for the next calculation
-    state.last_update_instant = Some(now_instant);
+#[tauri::command]
+fn export_history(app: AppHandle<Wry>, latest: State<'_, LatestSnapshot>, series: String, format: ExportFormat, path: Option<String>) -> Result<String, String> {
+    let guard = latest.0.lock().map_err(|e| format!("Mutex poisoned in command: {}", e))?;
+    let data = guard.as_ref().ok_or_else(|| "No data collected yet".to_string())?;
+    let table = exporters::file::history_table(data, &series).map_err(|e| e.to_string())?;
+    let path = export_path(&app, path, &series, format)?;
+    write_export(&path, |out| exporters::file::write_history(out, &table, format))
+}
 
-    (read_kb_per_sec, write_kb_per_sec)
+// Scans every process's fds, so it runs off the async runtime and only when asked for
+#[tauri::command]
+async fn get_network_connections() -> Result<Vec<NetworkConnection>, String> {
+    tokio::task::spawn_blocking(collectors::connections::list_connections)
+        .await
+        .map_err(|e| format!("Connection scan failed: {}", e))
+}
+
+// --- Process control ---
+#[tauri::command]
+async fn terminate_process(
+    pid: u32,
+    expected_name: Option<String>,
+    expected_start_time: Option<u64>,
+    timeout_ms: Option<u64>,
+) -> Result<ProcessActionResult, String> {
+    let expected = ExpectedProcess { name: expected_name, start_time: expected_start_time };
+    let timeout = std::time::Duration::from_millis(timeout_ms.unwrap_or(control::process::DEFAULT_TERMINATE_TIMEOUT_MS));
+    Ok(control::process::terminate(pid, &expected, timeout).await)
+}
+
+#[tauri::command]
+fn kill_process(pid: u32, expected_name: Option<String>, expected_start_time: Option<u64>) -> Result<ProcessActionResult, String> {
+    let expected = ExpectedProcess { name: expected_name, start_time: expected_start_time };
+    Ok(control::process::send_signal(pid, "KILL", &expected))
+}
+
+#[tauri::command]
+fn signal_process(
+    pid: u32,
+    signal: String,
+    expected_name: Option<String>,
+    expected_start_time: Option<u64>,
+) -> Result<ProcessActionResult, String> {
+    let expected = ExpectedProcess { name: expected_name, start_time: expected_start_time };
+    Ok(control::process::send_signal(pid, &signal, &expected))
+}
+
+#[tauri::command]
+fn kill_process_tree(
+    pid: u32,
+    signal: Option<String>,
+    expected_name: Option<String>,
+    expected_start_time: Option<u64>,
+) -> Result<Vec<ProcessActionResult>, String> {
+    let expected = ExpectedProcess { name: expected_name, start_time: expected_start_time };
+    Ok(control::process::signal_tree(pid, signal.as_deref().unwrap_or("KILL"), &expected))
+}
+
+#[tauri::command]
+fn kill_process_group(pgid: u32, signal: Option<String>) -> Result<ProcessActionResult, String> {
+    Ok(control::process::signal_group(pgid, signal.as_deref().unwrap_or("TERM")))
+}
+
+
+// --- Alerts ---
+#[tauri::command]
+fn list_alert_rules(state: State<'_, AlertState>) -> Result<Vec<AlertRule>, String> {
+    let guard = state.0.lock().map_err(|e| format!("Alert mutex poisoned: {}", e))?;
+    Ok(guard.rules().to_vec())
+}
+
+#[tauri::command]
+fn get_active_alerts(state: State<'_, AlertState>) -> Result<Vec<Alert>, String> {
+    let guard = state.0.lock().map_err(|e| format!("Alert mutex poisoned: {}", e))?;
+    Ok(guard.active_alerts())
+}
+
+#[tauri::command]
+fn save_alert_rule(app: AppHandle<Wry>, state: State<'_, AlertState>, rule: AlertRule) -> Result<Vec<AlertRule>, String> {
+    let rules = {
+        let mut guard = state.0.lock().map_err(|e| format!("Alert mutex poisoned: {}", e))?;
+        guard.upsert_rule(rule)?;
+        guard.rules().to_vec()
+    };
+    persist_alert_rules(&app, &rules)?;
+    Ok(rules)
+}
+
+#[tauri::command]
+fn delete_alert_rule(app: AppHandle<Wry>, state: State<'_, AlertState>, id: String) -> Result<Vec<AlertRule>, String> {
+    let rules = {
+        let mut guard = state.0.lock().map_err(|e| format!("Alert mutex poisoned: {}", e))?;
+        if !guard.remove_rule(&id) {
+            return Err(format!("Unknown alert rule '{}'", id));
+        }
+        guard.rules().to_vec()
+    };
+    persist_alert_rules(&app, &rules)?;
+    Ok(rules)
+}
+
+#[tauri::command]
+fn set_alert_rule_enabled(app: AppHandle<Wry>, state: State<'_, AlertState>, id: String, enabled: bool) -> Result<Vec<AlertRule>, String> {
+    let rules = {
+        let mut guard = state.0.lock().map_err(|e| format!("Alert mutex poisoned: {}", e))?;
+        if !guard.set_rule_enabled(&id, enabled) {
+            return Err(format!("Unknown alert rule '{}'", id));
+        }
+        guard.rules().to_vec()
+    };
+    persist_alert_rules(&app, &rules)?;
+    Ok(rules)
 }
 
 
 // --- Main Data Collection Logic ---
-fn collect_all_system_data_structured(
+// Public so the headless CLI binary can drive the same collection cycle as the GUI
+pub fn collect_all_system_data_structured(
     state: &mut AppStateInner,
 ) -> Result<SystemData, Box<dyn std::error::Error + Send + Sync>> {
     let now_chrono = chrono::Local::now();
     let now_instant = std::time::Instant::now(); // Use consistent time for all updates in cycle
 
-    // Phase 1: Refresh (Refreshes state.sys inside)
-    refresh_sysinfo(state);
-
-    // --- Calculate System Disk I/O early after process refresh ---
-    let (sys_read_kbps, sys_write_kbps) =
-        collect_and_update_system_disk_io(state, now_instant);
-    // ---
-
-    // Phase 2: Collect initial data (Uses refreshed state.sys)
-    let initial_data = collect_initial_data(state)?;
-
-    // Phase 3: Update basic histories (Needs &mut state for history fields)
-    update_basic_histories(&initial_data, state)?;
-
-    // Phase 4: Collect complex data (Needs &mut state for maps/history, uses state.sys)
-    let complex_data = collect_complex_data(state, &initial_data, now_instant)?;
-
-    // Phase 5: Convert process data for serialization
-    let process_data_serializable: Vec<(u32, String, f32, u64)> = initial_data
-        .process_data_raw
-        .into_iter()
-        .map(|(pid, name, cpu, mem)| (pid.as_u32(), name, cpu, mem))
-        .collect();
-
-    // Phase 6: Assemble and return the SystemData structure
-    Ok(SystemData {
-        cpu_usage: initial_data.cpu_usage.clone(),
-        cpu_history: state.cpu_history.clone(),
-        memory_used: initial_data.memory_used,
-        memory_total: initial_data.memory_total,
-        memory_history: state.memory_history.clone(),
-        top_processes: process_data_serializable,
-        network_data: complex_data.network_data,
-        gpu_data: complex_data.gpu_data,
-        disk_data: complex_data.disk_data, // Per-disk info (still no I/O here)
+    // Refresh only what the enabled collectors need
+    state.collectors.refresh_requirements().apply(&mut state.sys);
+
+    let mut data = SystemData {
         timestamp: now_chrono,
+        update_interval_ms: state.update_interval_ms,
         platform_name: utils::get_platform_name(),
-        // --- NEW: Populate system-wide disk fields ---
-        system_disk_read_per_sec: sys_read_kbps,
-        system_disk_write_per_sec: sys_write_kbps,
-        system_disk_read_history: state.system_disk_read_history.clone(),
-        system_disk_write_history: state.system_disk_write_history.clone(),
-        // --- End NEW ---
-    })
+        ..Default::default()
+    };
+
+    // Each enabled collector fills in its own part, in registry order
+    let ctx = collectors::CollectContext { sys: &state.sys, now: now_instant, timestamp: now_chrono };
+    state.collectors.collect_all(&ctx, &mut data);
+
+    Ok(data)
+}
+
+// Interval that skips missed ticks instead of bursting after a slow collection
+fn new_sampling_interval(interval_ms: u64) -> tokio::time::Interval {
+    let mut interval = tokio::time::interval(tokio::time::Duration::from_millis(interval_ms));
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    interval
 }
 
 // --- Background Monitoring Task ---
-async fn monitoring_loop(app_handle: AppHandle<Wry>) { /* ... same as before ... */
-    let mut interval = tokio::time::interval(tokio::time::Duration::from_millis(UPDATE_INTERVAL_MS)); // Corrected: Use tokio interval
-    log::info!("Monitoring loop starting (interval: {}ms).", UPDATE_INTERVAL_MS);
+async fn monitoring_loop(app_handle: AppHandle<Wry>, metrics: Option<MetricsHandle>) {
+    // Fall back to a local (never changing) sender if the managed one is missing;
+    // it must outlive the loop, otherwise changed() would resolve immediately forever.
+    let fallback_interval = SamplingInterval::default();
+    let mut interval_rx = match app_handle.try_state::<SamplingInterval>() {
+        Some(sampling) => sampling.0.subscribe(),
+        None => fallback_interval.0.subscribe(),
+    };
+    let mut interval_ms = *interval_rx.borrow_and_update();
+    let mut interval = new_sampling_interval(interval_ms);
+    log::info!("Monitoring loop starting (interval: {}ms).", interval_ms);
     loop {
-        interval.tick().await;
+        tokio::select! {
+            _ = interval.tick() => {}
+            _ = interval_rx.changed() => {
+                // Restart the timer so a shorter interval takes effect now, not after the old period
+                let requested = *interval_rx.borrow_and_update();
+                if requested != interval_ms {
+                    log::info!("Sampling interval changed: {}ms -> {}ms", interval_ms, requested);
+                    interval_ms = requested;
+                    interval = new_sampling_interval(interval_ms);
+                }
+                continue; // The new interval's first tick fires immediately
+            }
+        }
         if let Some(app_state_mutex) = app_handle.try_state::<AppState>() {
             let system_data_result = {
                 match app_state_mutex.0.lock() {
-                    Ok(mut app_state_guard) => collect_all_system_data_structured(&mut *app_state_guard),
+                    Ok(mut app_state_guard) => {
+                        app_state_guard.update_interval_ms = interval_ms;
+                        collect_all_system_data_structured(&mut *app_state_guard)
+                    }
                     Err(poisoned) => { /* ... error handling ... */ Err(Box::new(std::io::Error::new( std::io::ErrorKind::Other, format!("Mutex poisoned: {}", poisoned), )) as Box<dyn std::error::Error + Send + Sync>) }
                 }
             };
@@ -254,7 +527,15 @@ async fn monitoring_loop(app_handle: AppHandle<Wry>) { /* ... same as before ...
                         data.system_disk_read_per_sec,  // Log new system-wide value
                         data.system_disk_write_per_sec // Log new system-wide value
                     );
-                    if let Err(e) = app_handle.emit("system-update", &data) { /* ... error handling ... */ log::error!("Failed to emit system-update event: {}", e); }
+                    if let Some(metrics) = &metrics { metrics.update(&data); }
+                    record_history(&app_handle, &data);
+                    evaluate_alerts(&app_handle, &data);
+                    record_session(&app_handle, &data);
+                    // The replay owns system-update meanwhile
+                    if !replay_active(&app_handle) {
+                        if let Err(e) = app_handle.emit("system-update", &data) { /* ... error handling ... */ log::error!("Failed to emit system-update event: {}", e); }
+                    }
+                    store_latest_snapshot(&app_handle, data);
                 }
                 Err(e) => { /* ... error handling ... */
                     log::error!("Error collecting system data in loop: {}", e);
@@ -267,6 +548,140 @@ async fn monitoring_loop(app_handle: AppHandle<Wry>) { /* ... same as before ...
 }
 
 
+// Emits a recorded session through system-update, keeping the recorded spacing divided by
+// `speed`. Ends early when `stop` is set (stop_replay or a newer replay).
+fn replay_session(app_handle: AppHandle<Wry>, reader: SessionReader, speed: f64, position: Arc<AtomicU64>, stop: Arc<AtomicBool>) {
+    let mut previous_timestamp = None;
+    for frame in reader {
+        let frame = match frame {
+            Ok(frame) => frame,
+            Err(e) => {
+                log::error!("Replay stopped: {}", e);
+                if let Err(emit_err) = app_handle.emit("backend-error", format!("Replay stopped: {}", e)) { log::error!("Failed to emit backend-error event: {}", emit_err); }
+                break;
+            }
+        };
+        if let Some(previous) = previous_timestamp {
+            let delay_ms = ((frame.timestamp_ms - previous).max(0) as f64 / speed) as u64;
+            // Sleep in slices so stop_replay takes effect quickly even at slow speeds
+            let deadline = std::time::Instant::now() + std::time::Duration::from_millis(delay_ms);
+            while !stop.load(Ordering::Relaxed) && std::time::Instant::now() < deadline {
+                std::thread::sleep(deadline.saturating_duration_since(std::time::Instant::now()).min(std::time::Duration::from_millis(100)));
+            }
+        }
+        if stop.load(Ordering::Relaxed) {
+            return; // Whoever stopped us already cleared the handle
+        }
+        previous_timestamp = Some(frame.timestamp_ms);
+        if let Err(e) = app_handle.emit("system-update", &frame.data) { log::error!("Failed to emit replayed system-update event: {}", e); }
+        position.fetch_add(1, Ordering::Relaxed);
+    }
+
+    // Finished on its own: hand the event stream back to the live loop
+    if let Some(sessions) = app_handle.try_state::<SessionState>() {
+        if let Ok(mut guard) = sessions.0.lock() {
+            if guard.replay.as_ref().is_some_and(|replay| Arc::ptr_eq(&replay.stop, &stop)) {
+                guard.replay = None;
+            }
+            if let Err(e) = app_handle.emit("session-status", guard.status()) { log::error!("Failed to emit session-status event: {}", e); }
+        }
+    }
+    log::info!("Replay finished after {} frames", position.load(Ordering::Relaxed));
+}
+
+// Appends a snapshot to the session recording, if one is running
+fn record_session(app_handle: &AppHandle<Wry>, data: &SystemData) {
+    let Some(sessions) = app_handle.try_state::<SessionState>() else { return; };
+    match sessions.0.lock() {
+        Ok(mut guard) => {
+            let Some(recorder) = guard.recorder.as_mut() else { return; };
+            if let Err(e) = recorder.record(data) {
+                // Most likely a full disk; what was written so far stays replayable
+                log::error!("Session recording stopped: {}", e);
+                guard.recorder = None;
+                if let Err(emit_err) = app_handle.emit("backend-error", format!("Session recording stopped: {}", e)) { log::error!("Failed to emit backend-error event: {}", emit_err); }
+            }
+        }
+        Err(poisoned) => log::error!("Session mutex poisoned: {}", poisoned),
+    }
+}
+
+// Keeps the snapshot for exports; exports always use live data, even during a replay
+fn store_latest_snapshot(app_handle: &AppHandle<Wry>, data: SystemData) {
+    let Some(latest) = app_handle.try_state::<LatestSnapshot>() else { return; };
+    match latest.0.lock() {
+        Ok(mut guard) => *guard = Some(data),
+        Err(poisoned) => log::error!("Snapshot mutex poisoned: {}", poisoned),
+    }
+}
+
+fn replay_active(app_handle: &AppHandle<Wry>) -> bool {
+    app_handle.try_state::<SessionState>().is_some_and(|sessions| sessions.0.lock().is_ok_and(|guard| guard.replay.is_some()))
+}
+
+// Appends a snapshot to the on-disk history, if enabled
+fn record_history(app_handle: &AppHandle<Wry>, data: &SystemData) {
+    let Some(history) = app_handle.try_state::<HistoryState>() else { return; };
+    match history.0.lock() {
+        Ok(mut guard) => {
+            if let Some(store) = guard.as_mut() {
+                if let Err(e) = store.record(data) { log::warn!("Failed to record history sample: {}", e); }
+            }
+        }
+        Err(poisoned) => log::error!("History mutex poisoned: {}", poisoned),
+    }
+}
+
+// Runs the alert rules against a snapshot and emits alert-fired / alert-resolved
+fn evaluate_alerts(app_handle: &AppHandle<Wry>, data: &SystemData) {
+    let Some(alerts) = app_handle.try_state::<AlertState>() else { return; };
+    let events = match alerts.0.lock() {
+        Ok(mut engine) => engine.evaluate(data),
+        Err(poisoned) => { log::error!("Alert mutex poisoned: {}", poisoned); return; }
+    };
+    for event in events {
+        let result = match &event {
+            AlertEvent::Fired(alert) => app_handle.emit("alert-fired", alert),
+            AlertEvent::Resolved(alert) => app_handle.emit("alert-resolved", alert),
+        };
+        if let Err(e) = result { log::error!("Failed to emit alert event: {}", e); }
+    }
+}
+
+// Writes the current alert rules back to config.json, keeping every other section.
+// An unreadable config file is left alone rather than replaced with defaults.
+fn persist_alert_rules(app: &AppHandle<Wry>, rules: &[AlertRule]) -> Result<(), String> {
+    let path = app
+        .path()
+        .app_config_dir()
+        .map_err(|e| format!("Rule applied but not saved (no config dir): {}", e))?
+        .join(config::CONFIG_FILE_NAME);
+    let mut app_config = match std::fs::read_to_string(&path) {
+        Ok(content) => serde_json::from_str::<config::AppConfig>(&content)
+            .map_err(|e| format!("Rule applied but not saved: {} is invalid: {}", path.display(), e))?,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => config::AppConfig::default(),
+        Err(e) => return Err(format!("Rule applied but not saved: {}", e)),
+    };
+    app_config.alerts.rules = rules.to_vec();
+    app_config.save(&path).map_err(|e| format!("Rule applied but not saved: {}", e))
+}
+
+// Opens the history database in the app data directory
+fn open_history_store(app: &tauri::App<Wry>, history_config: &config::HistoryConfig) -> Option<HistoryStore> {
+    if !history_config.enabled {
+        log::info!("Persistent history disabled in config.");
+        return None;
+    }
+    let data_dir = match app.path().app_data_dir() {
+        Ok(dir) => dir,
+        Err(e) => { log::warn!("Could not resolve app data dir, history disabled: {}", e); return None; }
+    };
+    match HistoryStore::open(&data_dir.join("history.sqlite3"), history_config.retention_days) {
+        Ok(store) => Some(store),
+        Err(e) => { log::error!("Failed to open history database, history disabled: {}", e); None }
+    }
+}
+
 // --- Tauri Application Setup and Run (Keep using std::thread::spawn) ---
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() { /* ... same as before, ensures std::thread::spawn is used ... */
@@ -275,18 +690,77 @@ pub fn run() { /* ... same as before, ensures std::thread::spawn is used ... */
     log::info!("Starting System Monitor Pro Tauri Backend Setup");
     tauri::Builder::default()
         .manage(AppState::default())
+        .manage(SamplingInterval::default())
+        .manage(SessionState(Mutex::new(SessionControl::default())))
+        .manage(LatestSnapshot::default())
         .setup(|app| {
             log::info!("Running Tauri setup hook...");
             let app_handle = app.handle().clone();
+            let app_config = match app.path().app_config_dir() {
+                Ok(dir) => config::AppConfig::load(&dir.join(config::CONFIG_FILE_NAME)),
+                Err(e) => { log::warn!("Could not resolve app config dir: {}. Using defaults.", e); config::AppConfig::default() }
+            };
+            app.manage(HistoryState(Mutex::new(open_history_store(app, &app_config.history))));
+            app.manage(AlertState(Mutex::new(AlertEngine::new(app_config.alerts.enabled, app_config.alerts.rules.clone()))));
             std::thread::spawn(move || {
                 let rt = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime for monitoring thread");
-                rt.block_on(async move { monitoring_loop(app_handle).await; });
+                rt.block_on(async move {
+                    // Optional Prometheus endpoint, fed by the same loop that emits system-update
+                    let metrics = if app_config.metrics.enabled {
+                        let handle = MetricsHandle::new();
+                        let listen_address = app_config.metrics.listen_address.clone();
+                        let server_handle = handle.clone();
+                        tokio::spawn(async move {
+                            if let Err(e) = exporters::prometheus::serve(listen_address, server_handle).await {
+                                log::error!("Prometheus metrics endpoint stopped: {}", e);
+                            }
+                        });
+                        Some(handle)
+                    } else {
+                        None
+                    };
+                    monitoring_loop(app_handle, metrics).await;
+                });
                  log::info!("Monitoring thread (std::thread) finished block_on.");
             });
             log::info!("Monitoring task/thread spawned.");
             Ok(())
         })
-        .invoke_handler(tauri::generate_handler![ get_platform, get_current_system_data ])
+        .invoke_handler(tauri::generate_handler![
+            get_platform,
+            get_current_system_data,
+            query_metric_history,
+            list_history_series,
+            get_update_interval,
+            set_update_interval,
+            list_collectors,
+            set_collector_enabled,
+            set_collector_order,
+            get_process_table,
+            get_process_tree,
+            get_process_details,
+            get_unit_processes,
+            get_network_connections,
+            get_session_status,
+            list_sessions,
+            start_recording,
+            stop_recording,
+            start_replay,
+            stop_replay,
+            list_export_series,
+            export_snapshot,
+            export_history,
+            terminate_process,
+            kill_process,
+            signal_process,
+            kill_process_tree,
+            kill_process_group,
+            list_alert_rules,
+            get_active_alerts,
+            save_alert_rule,
+            delete_alert_rule,
+            set_alert_rule_enabled
+        ])
         .run(tauri::generate_context!())
         .expect("Error while running Tauri application");
 }
\ No newline at end of file
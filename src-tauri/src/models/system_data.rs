@@ -1,5 +1,6 @@
 // TauriViteReact/src-tauri/src/models/system_data.rs (Add System-Wide Disk I/O)
 
+use crate::collectors::CollectorRegistry;
 use chrono::{DateTime, Local};
 use serde::Serialize;
 use std::collections::HashMap;
@@ -8,7 +9,7 @@ use sysinfo::{System, SystemExt}; // Import System and SystemExt from sysinfo cr
 
 pub const HISTORY_LENGTH: usize = 100;
 
-// --- DiskData remains unchanged regarding I/O fields ---
+// --- DiskData: I/O rates come from per-device counters (Linux) ---
 #[derive(Serialize, Clone, Debug)]
 pub struct DiskData {
     pub name: String,
@@ -17,13 +18,14 @@ pub struct DiskData {
     pub total_space: f64,
     pub used_space: f64,
     pub used_percentage: f64,
-    pub read_bytes_per_sec: f64,  // Will remain 0
-    pub write_bytes_per_sec: f64, // Will remain 0
-    pub read_history: Vec<f64>,   // Will remain empty
-    pub write_history: Vec<f64>,  // Will remain empty
+    pub read_kb_per_sec: f64,  // KB/s, 0 where counters are unavailable
+    pub write_kb_per_sec: f64, // KB/s, 0 where counters are unavailable
+    pub read_history: Vec<f64>,
+    pub write_history: Vec<f64>,
+    pub history_timestamps: Vec<i64>, // Epoch ms of each read/write history sample
     #[serde(skip)]
     pub last_update_time: Option<Instant>, // Still used internally by collector
-    // Add fields to track previous read/write bytes
+    // Previous cumulative read/write bytes, used for rate deltas
     #[serde(skip)]
     pub last_read_bytes: Option<u64>,
     #[serde(skip)]
@@ -45,29 +47,255 @@ impl DiskData {
         };
         DiskData {
             name, mount_point, disk_type, total_space, used_space, used_percentage,
-            read_bytes_per_sec: 0.0, // Initialize to 0
-            write_bytes_per_sec: 0.0, // Initialize to 0
+            read_kb_per_sec: 0.0, // Initialize to 0
+            write_kb_per_sec: 0.0, // Initialize to 0
             read_history: Vec::with_capacity(HISTORY_LENGTH), // Initialize empty
             write_history: Vec::with_capacity(HISTORY_LENGTH), // Initialize empty
+            history_timestamps: Vec::with_capacity(HISTORY_LENGTH),
             last_update_time: None,
             last_read_bytes: None,
             last_write_bytes: None,
         }
     }
+}
+
+
+// --- CPU model, per-core frequency scaling, load and uptime ---
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct CpuCoreFrequency {
+    pub current_mhz: f64,
+    pub min_mhz: Option<f64>,  // Policy limits the governor works within (scaling_min/max_freq); None without cpufreq
+    pub max_mhz: Option<f64>,
+    pub hardware_min_mhz: Option<f64>, // What the core supports (cpuinfo_min/max_freq)
+    pub hardware_max_mhz: Option<f64>,
+    pub governor: Option<String>,                      // "schedutil", "powersave", "performance", ...
+    pub energy_performance_preference: Option<String>, // intel_pstate / amd-pstate EPP, e.g. "balance_performance"
+    pub history: Vec<f64>,                             // current_mhz
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct CpuInfo {
+    pub model: String,
+    pub vendor: String,
+    pub physical_cores: Option<usize>,
+    pub logical_cores: usize,
+    pub frequencies: Vec<CpuCoreFrequency>, // Per logical core, same order as cpu_usage
+    pub frequency_history_timestamps: Vec<i64>, // Epoch ms of each sample in the per-core frequency histories
+    pub load_average: [f64; 3],             // 1, 5 and 15 minutes (zeros on Windows)
+    pub uptime_secs: u64,
+    pub boot_time: u64,                     // Unix seconds
+}
+
+
+// --- CPU time categories from /proc/stat deltas, percent of the interval ---
+// user/nice exclude guest time (the kernel counts it in both), so the fields sum to 100.
+#[derive(Serialize, Clone, Copy, Debug, Default)]
+pub struct CpuTimes {
+    pub user: f64,
+    pub nice: f64,
+    pub system: f64,
+    pub idle: f64,
+    pub iowait: f64,
+    pub irq: f64,
+    pub softirq: f64,
+    pub steal: f64, // Taken by the hypervisor for other guests
+    pub guest: f64, // Spent running our own guests (guest + guest_nice)
+}
+
+impl CpuTimes {
+    /// Every category as (name, percent), in /proc/stat column order.
+    pub fn categories(&self) -> [(&'static str, f64); 9] {
+        [
+            ("user", self.user),
+            ("nice", self.nice),
+            ("system", self.system),
+            ("idle", self.idle),
+            ("iowait", self.iowait),
+            ("irq", self.irq),
+            ("softirq", self.softirq),
+            ("steal", self.steal),
+            ("guest", self.guest),
+        ]
+    }
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct CpuTimeBreakdown {
+    pub total: CpuTimes,
+    pub per_core: Vec<CpuTimes>, // Online cores in /proc/stat order
+    pub history: Vec<CpuTimes>,  // Aggregate, for the stacked chart next to cpu_history
+    pub history_timestamps: Vec<i64>,
+}
+
+
+// --- cgroup v2 accounting (Linux) ---
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct ContainerRef {
+    pub runtime: String,         // "docker", "podman", "containerd", "cri-o" or "kubernetes"
+    pub id: String,              // Full 64-hex container id
+    pub pod_uid: Option<String>, // Kubernetes pod UID
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct CgroupInfo {
+    pub path: String,                 // Relative to the unified root, e.g. "/system.slice/sshd.service"
+    pub name: String,                 // Last path component
+    pub container: Option<ContainerRef>,
+    pub owner_uid: Option<u32>,       // Set below user@UID.service (user services, rootless containers)
+    pub cpu_usage: f64,               // Percent of one core, like process CPU usage
+    pub memory_current_mb: Option<f64>,
+    pub memory_max_mb: Option<f64>,   // None when unlimited
+    pub io_read_kbps: f64,            // KB/s, needs the io controller
+    pub io_write_kbps: f64,
+    pub pids: Option<u64>,            // pids.current (tasks, including threads) for this subtree
+    pub process_count: usize,         // Processes directly in this cgroup
+}
+
+impl CgroupInfo {
+    /// Depth below the root ("/" is 0, "/system.slice" is 1)
+    pub fn depth(&self) -> usize {
+        self.path.split('/').filter(|c| !c.is_empty()).count()
+    }
+
+    /// Top-level slices and container cgroups (not the groups nested inside them); the stable
+    /// set per-cgroup pressure is read for, since services and session scopes come and go
+    pub fn is_headline(&self) -> bool {
+        self.depth() == 1 || self.container.as_ref().is_some_and(|container| self.name.contains(&container.id))
+    }
+}
+
+/// A systemd service or scope, taken from its cgroup. Usage covers the whole subtree.
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct SystemdUnit {
+    pub name: String,                 // e.g. "sshd.service", "session-3.scope"
+    pub unit_type: String,            // "service", "scope" or "session" (a login session scope)
+    pub cgroup_path: String,
+    pub slice: Option<String>,        // Nearest enclosing slice, e.g. "system.slice"
+    pub owner_uid: Option<u32>,       // User services and sessions
+    pub active_state: String,         // From cgroup.events: "active" (populated), "inactive" or "frozen"
+    pub cpu_usage: f64,               // Percent of one core
+    pub memory_mb: Option<f64>,
+    pub io_read_kbps: f64,
+    pub io_write_kbps: f64,
+    pub tasks: Option<u64>,           // pids.current
+    pub process_count: usize,         // Processes anywhere in the unit's subtree
+}
+
+// --- Pressure Stall Information (Linux 4.20+) ---
+/// One "some" or "full" line of a pressure file. "some": at least one task was stalled on the
+/// resource; "full": all non-idle tasks were stalled at once.
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct PressureStat {
+    pub avg10: f64,         // Percent of time stalled, kernel's running averages
+    pub avg60: f64,
+    pub avg300: f64,
+    pub total_us: u64,      // Cumulative stall time
+    pub stall_percent: f64, // Stall time per wall time since the previous sample, from total_us
+    pub history: Vec<f64>,  // History of stall_percent
+    pub history_timestamps: Vec<i64>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct ResourcePressure {
+    pub some: PressureStat,
+    pub full: Option<PressureStat>, // Missing on kernels before 5.13 for cpu
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct CgroupPressure {
+    pub path: String, // Same as CgroupInfo::path
+    pub cpu: Option<ResourcePressure>,
+    pub memory: Option<ResourcePressure>,
+    pub io: Option<ResourcePressure>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct PressureData {
+    pub cpu: Option<ResourcePressure>,
+    pub memory: Option<ResourcePressure>,
+    pub io: Option<ResourcePressure>,
+    pub cgroups: Vec<CgroupPressure>, // Top-level slices and containers (see CgroupInfo::is_headline)
+}
 
-    // update_io_and_history method is no longer called from the collector,
-    // but we can leave it here if needed elsewhere later.
-    // pub fn update_io_and_history(...) { ... }
+impl PressureData {
+    /// System-wide resources by name, for the history file export
+    pub fn resources(&self) -> [(&'static str, Option<&ResourcePressure>); 3] {
+        [("cpu", self.cpu.as_ref()), ("memory", self.memory.as_ref()), ("io", self.io.as_ref())]
+    }
+}
+
+
+// --- Memory breakdown from /proc/meminfo (Linux) ---
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct MemoryMetric {
+    pub value: f64, // GB
+    pub history: Vec<f64>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct MemoryBreakdown {
+    pub total: f64,                       // GB, MemTotal
+    pub available: MemoryMetric,          // MemAvailable: what can be allocated without swapping
+    pub free: MemoryMetric,               // MemFree: completely unused
+    pub anonymous: MemoryMetric,          // AnonPages: process heaps/stacks, the bulk of "used"
+    pub buffers: MemoryMetric,
+    pub cached: MemoryMetric,             // Page cache (Cached), reclaimable except for shmem
+    pub shared: MemoryMetric,             // Shmem: tmpfs and shared memory, counted in cached but not reclaimable
+    pub slab_reclaimable: MemoryMetric,   // SReclaimable: dentry/inode caches
+    pub slab_unreclaimable: MemoryMetric, // SUnreclaim
+    pub dirty: MemoryMetric,              // Waiting to be written back
+    pub writeback: MemoryMetric,          // Being written back right now
+    pub committed: MemoryMetric,          // Committed_AS: allocations promised to processes
+    pub commit_limit: f64,                // GB, CommitLimit (only enforced with vm.overcommit_memory=2)
+    pub hugepages_used: MemoryMetric,     // (HugePages_Total - HugePages_Free) * Hugepagesize
+    pub hugepages_total: u64,             // Pages
+    pub hugepages_free: u64,
+    pub hugepage_size_kb: u64,
+    pub history_timestamps: Vec<i64>,     // Epoch ms of each sample in the metric histories
+}
+
+
+// --- Network interface metadata (sysfs + getifaddrs; sparse outside Linux) ---
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct InterfaceInfo {
+    pub mac_address: Option<String>,
+    pub ipv4_addresses: Vec<String>, // CIDR notation, e.g. "192.168.1.10/24"
+    pub ipv6_addresses: Vec<String>,
+    pub mtu: Option<u32>,
+    pub operstate: Option<String>,   // "up", "down", "dormant", "unknown" (loopback/virtual), ...
+    pub speed_mbps: Option<u32>,     // None while the link is down or if the driver doesn't report it
+    pub duplex: Option<String>,      // "full" / "half"
+    pub driver: Option<String>,      // None for virtual interfaces
 }
 
+// Interface counters from /sys/class/net/<if>/statistics, either cumulative or per second
+#[derive(Serialize, Clone, Copy, Debug, Default)]
+pub struct InterfaceCounters<T> {
+    pub rx_packets: T,
+    pub tx_packets: T,
+    pub rx_errors: T,
+    pub tx_errors: T,
+    pub rx_dropped: T,
+    pub tx_dropped: T,
+    pub collisions: T,
+    pub multicast: T, // Received multicast packets
+}
 
-// --- NetworkData remains unchanged ---
+// --- NetworkData ---
 #[derive(Serialize, Clone, Debug, Default)]
 pub struct NetworkData {
     pub rx_history: Vec<f64>,
     pub tx_history: Vec<f64>,
     pub current_rx_speed: f64,
     pub current_tx_speed: f64,
+    pub info: InterfaceInfo,
+    pub counters: InterfaceCounters<u64>, // Totals since the interface came up
+    pub rates: InterfaceCounters<f64>,    // Per second over the last interval
+    pub error_history: Vec<f64>,          // rx + tx errors per second
+    pub drop_history: Vec<f64>,           // rx + tx drops per second
+    pub history_timestamps: Vec<i64>,     // Epoch ms of each sample in the histories above
+    #[serde(skip)]
+    pub last_counters: Option<(InterfaceCounters<u64>, Instant)>,
     #[serde(skip)]
     pub last_rx_bytes: u64,
     #[serde(skip)]
@@ -116,6 +344,94 @@ pub struct GpuData {
     pub memory_total: f64,
     pub power_usage: f64,
     pub utilization_history: Vec<f64>,
+    pub history_timestamps: Vec<i64>, // Filled in by GpuCollector
+}
+
+
+// --- Sensors: hwmon chips and thermal zones (Linux sysfs) ---
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum SensorKind {
+    Temperature, // °C
+    Fan,         // RPM
+    Voltage,     // V
+    Current,     // A
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct SensorReading {
+    pub id: String,    // Stable key, e.g. "coretemp.0/temp1" or "thermal_zone0"
+    pub kind: SensorKind,
+    pub label: String, // *_label if the driver provides one, else the input name / zone type
+    pub value: f64,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub crit: Option<f64>,
+    pub history: Vec<f64>,
+    pub history_timestamps: Vec<i64>,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct SensorChip {
+    pub id: String,             // Device the chip belongs to (e.g. "coretemp.0", "nvme0"), else the hwmonN name
+    pub name: String,           // Driver name from hwmon "name" (coretemp, k10temp, nvme, nct6775, ...)
+    pub sensors: Vec<SensorReading>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct SensorData {
+    pub chips: Vec<SensorChip>,
+    pub thermal_zones: Vec<SensorReading>,
+}
+
+
+// --- CPU power from RAPL energy counters (Linux powercap) ---
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct RaplDomain {
+    pub id: String,        // powercap zone, e.g. "intel-rapl:0:1"
+    pub name: String,      // "package-0", "package-0/core", "package-0/uncore", "package-0/dram", "psys"
+    pub energy_uj: u64,    // Raw counter; wraps at max_energy_range_uj
+    pub power_w: f64,      // Average over the last interval
+    pub history: Vec<f64>, // Starts one cycle later than the zone, which needs two readings
+    pub history_timestamps: Vec<i64>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct CpuPowerData {
+    pub domains: Vec<RaplDomain>,
+    pub package_power_w: f64,      // Sum of the package domains (core, uncore and on most parts dram included)
+    pub package_history: Vec<f64>,
+    pub package_history_timestamps: Vec<i64>,
+}
+
+
+// --- Power supplies: AC adapters and batteries (Linux sysfs) ---
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct BatteryInfo {
+    pub name: String,                        // power_supply entry, e.g. "BAT0"
+    pub manufacturer: Option<String>,
+    pub model_name: Option<String>,
+    pub technology: Option<String>,          // "Li-ion", "Li-poly", ...
+    pub status: String,                      // "Charging", "Discharging", "Full", "Not charging" or "Unknown"
+    pub capacity_percent: Option<f64>,
+    pub energy_now_wh: Option<f64>,          // Converted from charge_* (µAh) via the voltage where energy_* is missing
+    pub energy_full_wh: Option<f64>,
+    pub energy_full_design_wh: Option<f64>,
+    pub power_w: Option<f64>,                // Positive while charging, negative while discharging
+    pub voltage_v: Option<f64>,
+    pub cycle_count: Option<u32>,            // Many firmwares report 0 when they don't track it
+    pub health_percent: Option<f64>,         // energy_full against energy_full_design
+    pub time_to_empty_secs: Option<u64>,     // Estimated from the recent energy trend, only while discharging
+    pub time_to_full_secs: Option<u64>,      // Likewise, only while charging
+    pub capacity_history: Vec<f64>,
+    pub power_history: Vec<f64>,
+    pub history_timestamps: Vec<i64>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct PowerData {
+    pub ac_online: Option<bool>,      // Any mains/USB supply online; None without such a supply
+    pub batteries: Vec<BatteryInfo>,  // System batteries (not peripherals like mice)
 }
 
 
@@ -123,15 +439,27 @@ pub struct GpuData {
 #[derive(Serialize, Clone, Debug, Default)]
 pub struct SystemData {
     pub cpu_usage: Vec<f64>,
-    pub cpu_history: Vec<Vec<f64>>,
+    pub cpu_history: Vec<Vec<f64>>,         // Zero-padded to HISTORY_LENGTH from the start
+    pub cpu_history_timestamps: Vec<i64>,
+    pub cpu_info: CpuInfo,
+    pub cpu_times: Option<CpuTimeBreakdown>, // None where /proc/stat is unavailable
     pub memory_used: f64,
     pub memory_total: f64,
     pub memory_history: Vec<f64>,
+    pub memory_history_timestamps: Vec<i64>,
+    pub memory_details: Option<MemoryBreakdown>, // None where /proc/meminfo is unavailable
     pub top_processes: Vec<(u32, String, f32, u64)>,
     pub network_data: HashMap<String, NetworkData>,
     pub gpu_data: Vec<GpuData>,
-    pub disk_data: HashMap<String, DiskData>, // Per-disk info (no I/O rates/history)
+    pub disk_data: HashMap<String, DiskData>, // Per-disk info incl. I/O rates/history
+    pub sensors: SensorData,                  // Empty where hwmon/thermal sysfs is unavailable
+    pub power: Option<PowerData>,             // None without any power supply (most desktops, non-Linux)
+    pub cpu_power: Option<CpuPowerData>,      // None without readable RAPL counters (energy_uj is root-only on recent kernels)
+    pub cgroups: Vec<CgroupInfo>,             // Every cgroup v2 group, parents first; empty without cgroup v2
+    pub systemd_units: Vec<SystemdUnit>,      // Services and scopes found in the cgroup tree, by cgroup path
+    pub pressure: Option<PressureData>,       // None without /proc/pressure (kernel < 4.20 or psi=0)
     pub timestamp: DateTime<Local>,
+    pub update_interval_ms: u64,      // Sampling interval in effect for this snapshot
     pub platform_name: String,
 
     // --- NEW Fields ---
@@ -139,25 +467,18 @@ pub struct SystemData {
     pub system_disk_write_per_sec: f64, // System-wide disk write KB/s
     pub system_disk_read_history: Vec<f64>, // History of system_disk_read_per_sec
     pub system_disk_write_history: Vec<f64>, // History of system_disk_write_per_sec
+    pub system_disk_history_timestamps: Vec<i64>,
     // --- End NEW Fields ---
 }
 
 
-// --- AppStateInner: Add history vectors for system-wide disk I/O ---
-// --- Keep the rest as it was from previous step ---
+// --- AppStateInner: sysinfo handle + collector registry ---
+// Per-subsystem state (histories, previous counters) lives inside each collector.
 #[derive(Debug)]
 pub struct AppStateInner {
     pub sys: sysinfo::System,
-    pub cpu_history: Vec<Vec<f64>>,
-    pub memory_history: Vec<f64>,
-    pub gpu_utilization_history: HashMap<String, Vec<f64>>,
-    pub network_state: HashMap<String, NetworkData>,
-    pub disk_state: HashMap<String, DiskData>,
-    // --- NEW Fields ---
-    pub system_disk_read_history: Vec<f64>, // History for system-wide read rate
-    pub system_disk_write_history: Vec<f64>, // History for system-wide write rate
-    pub last_update_instant: Option<Instant>, // To calculate delta time for rates
-    // --- End NEW Fields ---
+    pub collectors: CollectorRegistry,
+    pub update_interval_ms: u64,      // Set by whoever drives collection (monitoring loop / CLI)
 }
 
 impl Default for AppStateInner {
@@ -169,16 +490,8 @@ impl Default for AppStateInner {
                 system.refresh_all();
                 system
             },
-            cpu_history: Vec::new(),
-            memory_history: Vec::new(),
-            gpu_utilization_history: HashMap::new(),
-            network_state: HashMap::new(),
-            disk_state: HashMap::new(),
-            // --- NEW Fields Init ---
-            system_disk_read_history: Vec::with_capacity(HISTORY_LENGTH),
-            system_disk_write_history: Vec::with_capacity(HISTORY_LENGTH),
-            last_update_instant: None,
-            // --- End NEW Fields Init ---
+            collectors: CollectorRegistry::with_builtin(),
+            update_interval_ms: 1000,
         }
     }
 }
@@ -0,0 +1,342 @@
+// TauriViteReact/src-tauri/src/storage/history.rs
+// SQLite-backed metric history. Every SystemData snapshot is flattened into
+// (metric, label, value) samples; each (metric, label) pair is one series.
+// Samples are kept at full resolution for RAW_WINDOW, then rolled up into 1-minute averages.
+
+use crate::models::SystemData;
+use crate::utils::error::Result;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+const PRUNE_EVERY: Duration = Duration::from_secs(60 * 60);
+const MS_PER_DAY: i64 = 24 * 60 * 60 * 1000;
+const RAW_WINDOW_MS: i64 = 6 * 60 * 60 * 1000; // Full-resolution samples younger than this
+const ROLLUP_MS: i64 = 60 * 1000;              // Older samples become one average per minute
+
+/// A time range of one series, shaped for `HistoricalChart` (`data` + `timestamps`).
+#[derive(Serialize, Clone, Debug)]
+pub struct HistorySeries {
+    pub metric: String,
+    pub label: String,
+    pub timestamps: Vec<i64>, // Unix epoch milliseconds
+    pub values: Vec<f64>,
+}
+
+/// A stored series and the time span it covers.
+#[derive(Serialize, Clone, Debug)]
+pub struct HistorySeriesInfo {
+    pub metric: String,
+    pub label: String,
+    pub first_timestamp: i64,
+    pub last_timestamp: i64,
+    pub sample_count: u64,
+}
+
+/// One recorded metric: its name, unit and how to read its (label, value) samples from a snapshot.
+/// Units match SystemData (KB/s for rates, GB for sizes, percent, °C, W); `label` is empty for
+/// single-series metrics.
+pub struct MetricDef {
+    pub name: &'static str,
+    pub unit: &'static str,
+    samples: fn(&SystemData) -> Vec<(String, f64)>,
+}
+
+// Shorthand for single-series metrics
+fn single(value: f64) -> Vec<(String, f64)> {
+    vec![(String::new(), value)]
+}
+
+/// Every metric the history store records. `flatten_samples`, alert rule validation and
+/// exports all read this table, so a metric only has to be added here.
+pub const METRICS: &[MetricDef] = &[
+    MetricDef {
+        name: "cpu_usage",
+        unit: "%",
+        samples: |d| d.cpu_usage.iter().enumerate().map(|(core, usage)| (core.to_string(), *usage)).collect(),
+    },
+    MetricDef {
+        name: "cpu_usage_avg",
+        unit: "%",
+        samples: |d| {
+            if d.cpu_usage.is_empty() {
+                return Vec::new();
+            }
+            single(d.cpu_usage.iter().sum::<f64>() / d.cpu_usage.len() as f64)
+        },
+    },
+    MetricDef { name: "memory_used_gb", unit: "GB", samples: |d| single(d.memory_used) },
+    MetricDef {
+        name: "memory_percent",
+        unit: "%",
+        samples: |d| {
+            if d.memory_total <= 0.0 {
+                return Vec::new();
+            }
+            single(d.memory_used / d.memory_total * 100.0)
+        },
+    },
+    MetricDef {
+        name: "network_rx_kbps",
+        unit: "KB/s",
+        samples: |d| d.network_data.iter().map(|(name, net)| (name.clone(), net.current_rx_speed)).collect(),
+    },
+    MetricDef {
+        name: "network_tx_kbps",
+        unit: "KB/s",
+        samples: |d| d.network_data.iter().map(|(name, net)| (name.clone(), net.current_tx_speed)).collect(),
+    },
+    MetricDef {
+        name: "disk_used_percent",
+        unit: "%",
+        samples: |d| d.disk_data.iter().map(|(name, disk)| (name.clone(), disk.used_percentage)).collect(),
+    },
+    MetricDef {
+        name: "disk_read_kbps",
+        unit: "KB/s",
+        samples: |d| d.disk_data.iter().map(|(name, disk)| (name.clone(), disk.read_kb_per_sec)).collect(),
+    },
+    MetricDef {
+        name: "disk_write_kbps",
+        unit: "KB/s",
+        samples: |d| d.disk_data.iter().map(|(name, disk)| (name.clone(), disk.write_kb_per_sec)).collect(),
+    },
+    MetricDef { name: "system_disk_read_kbps", unit: "KB/s", samples: |d| single(d.system_disk_read_per_sec) },
+    MetricDef { name: "system_disk_write_kbps", unit: "KB/s", samples: |d| single(d.system_disk_write_per_sec) },
+    // GPUs are labelled by index like the Prometheus exporter, so identical cards don't share a series
+    MetricDef {
+        name: "gpu_utilization",
+        unit: "%",
+        samples: |d| d.gpu_data.iter().enumerate().map(|(i, gpu)| (i.to_string(), gpu.utilization)).collect(),
+    },
+    MetricDef {
+        name: "gpu_temperature",
+        unit: "°C",
+        samples: |d| d.gpu_data.iter().enumerate().map(|(i, gpu)| (i.to_string(), gpu.temperature)).collect(),
+    },
+    MetricDef {
+        name: "gpu_memory_used_gb",
+        unit: "GB",
+        samples: |d| d.gpu_data.iter().enumerate().map(|(i, gpu)| (i.to_string(), gpu.memory_used)).collect(),
+    },
+    MetricDef {
+        name: "gpu_power_w",
+        unit: "W",
+        samples: |d| d.gpu_data.iter().enumerate().map(|(i, gpu)| (i.to_string(), gpu.power_usage)).collect(),
+    },
+];
+
+/// Looks up a metric by name.
+pub fn metric(name: &str) -> Option<&'static MetricDef> {
+    METRICS.iter().find(|metric| metric.name == name)
+}
+
+/// Flattens a snapshot into (metric, label, value) samples, one group per entry of `METRICS`.
+pub fn flatten_samples(data: &SystemData) -> Vec<(&'static str, String, f64)> {
+    METRICS
+        .iter()
+        .flat_map(|metric| (metric.samples)(data).into_iter().map(|(label, value)| (metric.name, label, value)))
+        .collect()
+}
+
+pub struct HistoryStore {
+    conn: Connection,
+    series_ids: HashMap<(String, String), i64>, // Cache of (metric, label) -> series.id
+    retention_days: u32,                         // 0 keeps everything
+    last_prune: Option<Instant>,
+}
+
+impl HistoryStore {
+    /// Opens (or creates) the database at `path`.
+    pub fn open(path: &Path, retention_days: u32) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let conn = Connection::open(path)?;
+        // WAL keeps the once-per-interval writes from blocking range queries
+        conn.pragma_update(None, "journal_mode", "WAL")?;
+        conn.pragma_update(None, "synchronous", "NORMAL")?;
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS series (
+                 id     INTEGER PRIMARY KEY,
+                 metric TEXT NOT NULL,
+                 label  TEXT NOT NULL,
+                 UNIQUE (metric, label)
+             );
+             CREATE TABLE IF NOT EXISTS samples (
+                 series_id INTEGER NOT NULL REFERENCES series(id),
+                 ts_ms     INTEGER NOT NULL,
+                 value     REAL NOT NULL,
+                 PRIMARY KEY (series_id, ts_ms)
+             ) WITHOUT ROWID;
+             CREATE INDEX IF NOT EXISTS samples_ts ON samples (ts_ms);
+             CREATE TABLE IF NOT EXISTS samples_1m (
+                 series_id INTEGER NOT NULL REFERENCES series(id),
+                 ts_ms     INTEGER NOT NULL, -- Start of the minute
+                 value     REAL NOT NULL,
+                 PRIMARY KEY (series_id, ts_ms)
+             ) WITHOUT ROWID;
+             CREATE INDEX IF NOT EXISTS samples_1m_ts ON samples_1m (ts_ms);",
+        )?;
+        log::info!("Opened metric history at {} (retention: {} days)", path.display(), retention_days);
+
+        Ok(HistoryStore { conn, series_ids: HashMap::new(), retention_days, last_prune: None })
+    }
+
+    /// Writes every sample of a snapshot in one transaction; rolls up and prunes old data hourly.
+    pub fn record(&mut self, data: &SystemData) -> Result<()> {
+        let ts_ms = data.timestamp.timestamp_millis();
+        let samples = flatten_samples(data);
+
+        let tx = self.conn.transaction()?;
+        {
+            let mut insert = tx.prepare_cached(
+                "INSERT OR REPLACE INTO samples (series_id, ts_ms, value) VALUES (?1, ?2, ?3)",
+            )?;
+            for (metric, label, value) in samples {
+                if !value.is_finite() {
+                    continue;
+                }
+                let key = (metric.to_string(), label);
+                let series_id = match self.series_ids.get(&key) {
+                    Some(id) => *id,
+                    None => {
+                        let id = series_id(&tx, &key.0, &key.1)?;
+                        self.series_ids.insert(key, id);
+                        id
+                    }
+                };
+                insert.execute(params![series_id, ts_ms, value])?;
+            }
+        }
+        tx.commit()?;
+
+        if self.last_prune.is_none_or(|last| last.elapsed() >= PRUNE_EVERY) {
+            self.roll_up(ts_ms)?;
+            self.prune(ts_ms)?;
+            self.last_prune = Some(Instant::now());
+        }
+        Ok(())
+    }
+
+    /// Replaces raw samples older than the raw window with per-minute averages. Returns the
+    /// number of raw rows rolled up.
+    pub fn roll_up(&mut self, now_ms: i64) -> Result<usize> {
+        // Whole minutes only, so a minute is never split between two roll-ups
+        let cutoff = (now_ms - RAW_WINDOW_MS).div_euclid(ROLLUP_MS) * ROLLUP_MS;
+        let tx = self.conn.transaction()?;
+        tx.execute(
+            "INSERT OR REPLACE INTO samples_1m (series_id, ts_ms, value)
+             SELECT series_id, (ts_ms / ?2) * ?2, AVG(value)
+             FROM samples WHERE ts_ms < ?1
+             GROUP BY series_id, ts_ms / ?2",
+            params![cutoff, ROLLUP_MS],
+        )?;
+        let rolled_up = tx.execute("DELETE FROM samples WHERE ts_ms < ?1", params![cutoff])?;
+        tx.commit()?;
+        if rolled_up > 0 {
+            log::debug!("Rolled up {} history samples into 1-minute averages", rolled_up);
+        }
+        Ok(rolled_up)
+    }
+
+    /// Deletes samples older than the retention window. Returns the number of rows removed.
+    pub fn prune(&mut self, now_ms: i64) -> Result<usize> {
+        if self.retention_days == 0 {
+            return Ok(0);
+        }
+        let cutoff = now_ms - self.retention_days as i64 * MS_PER_DAY;
+        let removed = self.conn.execute("DELETE FROM samples WHERE ts_ms < ?1", params![cutoff])?
+            + self.conn.execute("DELETE FROM samples_1m WHERE ts_ms < ?1", params![cutoff])?;
+        if removed > 0 {
+            log::debug!("Pruned {} history samples older than {} days", removed, self.retention_days);
+        }
+        Ok(removed)
+    }
+
+    /// Returns `metric` between `start_ms` and `end_ms` (inclusive), one entry per label
+    /// (or only `label` if given), from raw samples and minute averages alike. With `max_points`,
+    /// samples are averaged into that many time buckets so a week of data stays chartable.
+    pub fn query(
+        &self,
+        metric: &str,
+        label: Option<&str>,
+        start_ms: i64,
+        end_ms: i64,
+        max_points: Option<usize>,
+    ) -> Result<Vec<HistorySeries>> {
+        let bucket_ms = match max_points {
+            Some(points) if points > 0 => ((end_ms - start_ms) / points as i64).max(1),
+            _ => 1,
+        };
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT s.label, CAST(AVG(t.ts_ms) AS INTEGER), AVG(t.value)
+             FROM (SELECT * FROM samples UNION ALL SELECT * FROM samples_1m) t
+             JOIN series s ON s.id = t.series_id
+             WHERE s.metric = ?1 AND (?2 IS NULL OR s.label = ?2)
+               AND t.ts_ms BETWEEN ?3 AND ?4
+             GROUP BY s.id, (t.ts_ms - ?3) / ?5
+             ORDER BY s.label, 2",
+        )?;
+        let rows = stmt.query_map(params![metric, label, start_ms, end_ms, bucket_ms], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?, row.get::<_, f64>(2)?))
+        })?;
+
+        let mut series: Vec<HistorySeries> = Vec::new();
+        for row in rows {
+            let (label, ts, value) = row?;
+            match series.last_mut() {
+                Some(current) if current.label == label => {
+                    current.timestamps.push(ts);
+                    current.values.push(value);
+                }
+                _ => series.push(HistorySeries {
+                    metric: metric.to_string(),
+                    label,
+                    timestamps: vec![ts],
+                    values: vec![value],
+                }),
+            }
+        }
+        Ok(series)
+    }
+
+    /// Lists every series that still has samples (`sample_count` counts a minute average as one).
+    pub fn list_series(&self) -> Result<Vec<HistorySeriesInfo>> {
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT s.metric, s.label, MIN(t.ts_ms), MAX(t.ts_ms), COUNT(*)
+             FROM (SELECT * FROM samples UNION ALL SELECT * FROM samples_1m) t
+             JOIN series s ON s.id = t.series_id
+             GROUP BY s.id
+             ORDER BY s.metric, s.label",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok(HistorySeriesInfo {
+                metric: row.get(0)?,
+                label: row.get(1)?,
+                first_timestamp: row.get(2)?,
+                last_timestamp: row.get(3)?,
+                sample_count: row.get::<_, i64>(4)? as u64,
+            })
+        })?;
+        Ok(rows.collect::<std::result::Result<Vec<_>, _>>()?)
+    }
+}
+
+// Looks up or creates the id for a (metric, label) series
+fn series_id(conn: &Connection, metric: &str, label: &str) -> Result<i64> {
+    let existing = conn
+        .query_row(
+            "SELECT id FROM series WHERE metric = ?1 AND label = ?2",
+            params![metric, label],
+            |row| row.get(0),
+        )
+        .optional()?;
+    if let Some(id) = existing {
+        return Ok(id);
+    }
+    conn.execute("INSERT INTO series (metric, label) VALUES (?1, ?2)", params![metric, label])?;
+    Ok(conn.last_insert_rowid())
+}
@@ -0,0 +1,8 @@
+// TauriViteReact/src-tauri/src/storage/mod.rs
+
+// On-disk persistence that outlives the in-memory HISTORY_LENGTH buffers
+pub mod history;
+pub mod session;
+
+pub use history::{flatten_samples, metric, HistorySeries, HistorySeriesInfo, HistoryStore, MetricDef, METRICS};
+pub use session::{RecordingStatus, ReplayStatus, SessionInfo, SessionReader, SessionRecorder, SessionStatus};
@@ -0,0 +1,339 @@
+// TauriViteReact/src-tauri/src/storage/session.rs
+// Recorded SystemData sessions: every emitted snapshot appended to a JSON-lines file, for
+// replaying incidents and for frontend work against realistic data.
+//
+// File layout: a header line, then one `{"t": <epoch ms>, "d": <snapshot>}` line per frame.
+// Snapshots are delta-encoded against the previous frame: the in-memory histories (most of a
+// snapshot) only move by one sample per cycle, so an array that is the previous one plus a new
+// value is stored as `{"+": value}`, or `{">": value}` when the oldest value was dropped.
+
+use crate::models::SystemData;
+use crate::utils::error::{MonitorError, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, BufWriter, Lines, Write};
+use std::path::{Path, PathBuf};
+
+pub const SESSION_EXTENSION: &str = "smsession";
+const SESSION_FORMAT: &str = "system-monitor-session";
+const SESSION_VERSION: u32 = 1;
+
+// Delta markers; SystemData has no single-key objects with these names
+const APPEND: &str = "+";
+const SHIFT_APPEND: &str = ">";
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SessionHeader {
+    pub format: String,
+    pub version: u32,
+    pub platform: String,
+    pub started: i64,          // Epoch ms
+    pub interval_ms: u64,      // Sampling interval when recording started
+}
+
+/// A recorded session on disk, for the session list.
+#[derive(Serialize, Clone, Debug)]
+pub struct SessionInfo {
+    pub path: String,
+    pub name: String,
+    pub size_bytes: u64,
+    pub header: Option<SessionHeader>, // None if the file isn't a readable session
+}
+
+/// What the recorder and the replayer are doing, for the session controls.
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct SessionStatus {
+    pub recording: Option<RecordingStatus>,
+    pub replay: Option<ReplayStatus>,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct RecordingStatus {
+    pub path: String,
+    pub frames: u64,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct ReplayStatus {
+    pub path: String,
+    pub position: u64, // Frames emitted so far
+    pub total: u64,
+    pub speed: f64,    // 1.0 = recorded pace
+}
+
+/// One replayed snapshot, kept as JSON: it is only re-emitted, never inspected.
+pub struct SessionFrame {
+    pub timestamp_ms: i64,
+    pub data: Value,
+}
+
+#[derive(Serialize, Deserialize)]
+struct FrameLine {
+    t: i64,
+    d: Value,
+}
+
+// --- Recording ---
+
+/// Appends snapshots to a session file. Each frame is flushed, so a crash loses at most the last one.
+pub struct SessionRecorder {
+    writer: BufWriter<File>,
+    path: PathBuf,
+    previous: Value,
+    frames: u64,
+}
+
+impl SessionRecorder {
+    pub fn create(path: &Path, platform: &str, interval_ms: u64) -> Result<Self> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let file = OpenOptions::new().write(true).create_new(true).open(path)?;
+        let mut writer = BufWriter::new(file);
+        let header = SessionHeader {
+            format: SESSION_FORMAT.to_string(),
+            version: SESSION_VERSION,
+            platform: platform.to_string(),
+            started: chrono::Local::now().timestamp_millis(),
+            interval_ms,
+        };
+        write_line(&mut writer, &header)?;
+        Ok(SessionRecorder { writer, path: path.to_path_buf(), previous: Value::Null, frames: 0 })
+    }
+
+    pub fn record(&mut self, data: &SystemData) -> Result<()> {
+        let current = serde_json::to_value(data).map_err(|e| MonitorError::Storage(e.to_string()))?;
+        let line = FrameLine { t: data.timestamp.timestamp_millis(), d: encode(&self.previous, &current) };
+        write_line(&mut self.writer, &line)?;
+        self.previous = current;
+        self.frames += 1;
+        Ok(())
+    }
+
+    pub fn path(&self) -> &Path { &self.path }
+
+    pub fn frames(&self) -> u64 { self.frames }
+}
+
+fn write_line<T: Serialize>(writer: &mut BufWriter<File>, value: &T) -> Result<()> {
+    serde_json::to_writer(&mut *writer, value).map_err(|e| MonitorError::Storage(e.to_string()))?;
+    writer.write_all(b"\n")?;
+    writer.flush()?;
+    Ok(())
+}
+
+// --- Replay ---
+
+/// Reads a session frame by frame, undoing the delta encoding as it goes.
+pub struct SessionReader {
+    pub header: SessionHeader,
+    lines: Lines<BufReader<File>>,
+    previous: Value,
+}
+
+impl SessionReader {
+    pub fn open(path: &Path) -> Result<Self> {
+        let mut lines = BufReader::new(File::open(path)?).lines();
+        let first = lines.next().transpose()?.unwrap_or_default();
+        let header: SessionHeader = serde_json::from_str(&first)
+            .ok()
+            .filter(|header: &SessionHeader| header.format == SESSION_FORMAT)
+            .ok_or_else(|| MonitorError::Storage(format!("{} is not a session file", path.display())))?;
+        if header.version > SESSION_VERSION {
+            return Err(MonitorError::Storage(format!("Session format version {} is newer than supported ({})", header.version, SESSION_VERSION)));
+        }
+        Ok(SessionReader { header, lines, previous: Value::Null })
+    }
+}
+
+impl Iterator for SessionReader {
+    type Item = Result<SessionFrame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let line = match self.lines.next()? {
+            Ok(line) => line,
+            Err(e) => return Some(Err(e.into())),
+        };
+        // A recording cut off mid-write ends with a partial line; treat it as the end
+        let frame: FrameLine = serde_json::from_str(&line).ok()?;
+        let data = match decode(&self.previous, frame.d) {
+            Ok(data) => data,
+            Err(e) => return Some(Err(e)),
+        };
+        self.previous = data.clone();
+        Some(Ok(SessionFrame { timestamp_ms: frame.t, data }))
+    }
+}
+
+/// Number of frames in a session (lines after the header), without decoding them.
+pub fn count_frames(path: &Path) -> Result<u64> {
+    let mut count = 0;
+    for line in BufReader::new(File::open(path)?).lines().skip(1) {
+        if !line?.trim().is_empty() {
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+/// Session files in `dir`, newest first.
+pub fn list_sessions(dir: &Path) -> Vec<SessionInfo> {
+    let Ok(entries) = fs::read_dir(dir) else { return Vec::new() };
+    let mut sessions: Vec<SessionInfo> = entries
+        .flatten()
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == SESSION_EXTENSION))
+        .map(|entry| {
+            let path = entry.path();
+            SessionInfo {
+                name: entry.file_name().to_string_lossy().into_owned(),
+                size_bytes: entry.metadata().map(|m| m.len()).unwrap_or(0),
+                header: SessionReader::open(&path).ok().map(|reader| reader.header),
+                path: path.to_string_lossy().into_owned(),
+            }
+        })
+        .collect();
+    sessions.sort_by(|a, b| b.header.as_ref().map(|h| h.started).cmp(&a.header.as_ref().map(|h| h.started)).then(b.name.cmp(&a.name)));
+    sessions
+}
+
+// --- Delta encoding ---
+
+// `current` relative to `previous`; objects and arrays are encoded member by member
+fn encode(previous: &Value, current: &Value) -> Value {
+    match current {
+        Value::Array(items) => {
+            if let (Value::Array(before), Some(last)) = (previous, items.last()) {
+                let n = items.len();
+                if n >= 2 && n == before.len() + 1 && items[..n - 1] == before[..] {
+                    return single(APPEND, last.clone());
+                }
+                if n >= 2 && n == before.len() && items[..n - 1] == before[1..] {
+                    return single(SHIFT_APPEND, last.clone());
+                }
+            }
+            Value::Array(items.iter().enumerate().map(|(i, item)| encode(previous.get(i).unwrap_or(&Value::Null), item)).collect())
+        }
+        Value::Object(fields) => Value::Object(
+            fields.iter().map(|(key, value)| (key.clone(), encode(previous.get(key).unwrap_or(&Value::Null), value))).collect(),
+        ),
+        _ => current.clone(),
+    }
+}
+
+fn decode(previous: &Value, encoded: Value) -> Result<Value> {
+    match encoded {
+        Value::Object(mut fields) if fields.len() == 1 && (fields.contains_key(APPEND) || fields.contains_key(SHIFT_APPEND)) => {
+            let Value::Array(mut items) = previous.clone() else {
+                return Err(MonitorError::Storage("Corrupt session: history delta without a previous frame".to_string()));
+            };
+            if let Some(value) = fields.remove(SHIFT_APPEND) {
+                if !items.is_empty() {
+                    items.remove(0);
+                }
+                items.push(value);
+            } else if let Some(value) = fields.remove(APPEND) {
+                items.push(value);
+            }
+            Ok(Value::Array(items))
+        }
+        Value::Object(fields) => {
+            let mut decoded = Map::with_capacity(fields.len());
+            for (key, value) in fields {
+                let before = previous.get(&key).unwrap_or(&Value::Null);
+                decoded.insert(key, decode(before, value)?);
+            }
+            Ok(Value::Object(decoded))
+        }
+        Value::Array(items) => items
+            .into_iter()
+            .enumerate()
+            .map(|(i, item)| decode(previous.get(i).unwrap_or(&Value::Null), item))
+            .collect::<Result<Vec<_>>>()
+            .map(Value::Array),
+        other => Ok(other),
+    }
+}
+
+fn single(key: &str, value: Value) -> Value {
+    let mut map = Map::with_capacity(1);
+    map.insert(key.to_string(), value);
+    Value::Object(map)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::HISTORY_LENGTH;
+    use serde_json::json;
+
+    // Encodes the frames one after another like the recorder, returning the encoded lines
+    fn encode_all(frames: &[Value]) -> Vec<Value> {
+        let mut previous = Value::Null;
+        frames
+            .iter()
+            .map(|frame| {
+                let encoded = encode(&previous, frame);
+                previous = frame.clone();
+                encoded
+            })
+            .collect()
+    }
+
+    // Decodes the encoded lines like the reader and checks every frame comes back unchanged
+    fn assert_round_trip(frames: &[Value]) -> Vec<Value> {
+        let encoded = encode_all(frames);
+        let mut previous = Value::Null;
+        for (index, (line, frame)) in encoded.iter().zip(frames).enumerate() {
+            let decoded = decode(&previous, line.clone()).unwrap();
+            assert_eq!(&decoded, frame, "frame {} differs after decoding", index);
+            previous = decoded;
+        }
+        encoded
+    }
+
+    fn history(range: std::ops::Range<usize>) -> Value {
+        Value::Array(range.map(|i| json!(i as f64 * 0.5)).collect())
+    }
+
+    #[test]
+    fn growing_history_is_stored_as_appends() {
+        let frames: Vec<Value> = (1..=HISTORY_LENGTH).map(|len| json!({ "memory_history": history(0..len) })).collect();
+        let encoded = assert_round_trip(&frames);
+
+        let last = &encoded[HISTORY_LENGTH - 1]["memory_history"];
+        assert_eq!(last, &json!({ APPEND: (HISTORY_LENGTH - 1) as f64 * 0.5 }));
+    }
+
+    #[test]
+    fn full_history_is_stored_as_shifts() {
+        let frames: Vec<Value> = (0..5).map(|start| json!({ "memory_history": history(start..start + HISTORY_LENGTH) })).collect();
+        let encoded = assert_round_trip(&frames);
+
+        for line in &encoded[1..] {
+            assert!(line["memory_history"].get(SHIFT_APPEND).is_some(), "expected a shift, got {}", line);
+        }
+    }
+
+    #[test]
+    fn changed_plain_arrays_round_trip() {
+        let frames = vec![
+            json!({ "cpu_usage": [10.0, 20.0, 30.0], "top_processes": [[1, "init", 0.5, 12]] }),
+            json!({ "cpu_usage": [15.0, 25.0, 35.0], "top_processes": [[1, "init", 0.7, 12], [42, "sh", 1.0, 3]] }),
+            json!({ "cpu_usage": [25.0, 35.0, 5.0], "top_processes": [] }),
+            json!({ "cpu_usage": [], "top_processes": [[42, "sh", 2.0, 3]] }),
+        ];
+        assert_round_trip(&frames);
+    }
+
+    #[test]
+    fn added_and_removed_keys_round_trip() {
+        let frames = vec![
+            json!({ "network_data": { "eth0": { "rx_history": [1.0] } }, "power": null }),
+            json!({ "network_data": { "eth0": { "rx_history": [1.0, 2.0] }, "wlan0": { "rx_history": [7.0] } }, "power": null }),
+            json!({ "network_data": { "wlan0": { "rx_history": [7.0, 8.0] } }, "power": { "ac_online": true } }),
+            json!({ "network_data": { "eth0": { "rx_history": [3.0] }, "wlan0": { "rx_history": [7.0, 8.0, 9.0] } } }),
+        ];
+        assert_round_trip(&frames);
+    }
+}
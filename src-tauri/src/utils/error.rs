@@ -9,6 +9,7 @@ pub enum MonitorError { // Ensure this name matches imports
     GpuInfo(String),
     NetworkInfo(String),
     DiskInfo(String),
+    Storage(String),
     IoError(std::io::Error),
     Other(String),
 }
@@ -20,6 +21,7 @@ impl fmt::Display for MonitorError {
             MonitorError::GpuInfo(msg) => write!(f, "GPU info error: {}", msg),
             MonitorError::NetworkInfo(msg) => write!(f, "Network info error: {}", msg),
             MonitorError::DiskInfo(msg) => write!(f, "Disk info error: {}", msg),
+            MonitorError::Storage(msg) => write!(f, "Storage error: {}", msg),
             MonitorError::IoError(err) => write!(f, "I/O error: {}", err),
             MonitorError::Other(msg) => write!(f, "Error: {}", msg),
         }
@@ -48,5 +50,11 @@ impl From<std::io::Error> for MonitorError {
     }
 }
 
+impl From<rusqlite::Error> for MonitorError {
+    fn from(err: rusqlite::Error) -> MonitorError {
+        MonitorError::Storage(err.to_string())
+    }
+}
+
 // Add other From impls if needed (e.g., for NvmlError if using nvml-wrapper)
 // impl From<nvml_wrapper::error::NvmlError> for MonitorError { ... }
\ No newline at end of file
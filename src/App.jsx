@@ -18,6 +18,7 @@ import { BsGpuCard } from "react-icons/bs";
 import { setLoading, updateSystemData, setDataFetchError } from './features/systemData/systemDataSlice';
 import { setCurrentTab, toggleDarkMode } from './features/ui/uiSlice'; // Removed setDarkMode unless needed
 import { setBackendError, clearBackendError } from './features/error/errorSlice';
+import { setActiveAlerts, alertFired, alertResolved } from './features/alerts/alertsSlice';
 import OverviewTab from './components/OverviewTab'; // Expecting Tailwind refactored version
 import CpuTab from './components/CpuTab';
 import MemoryTab from './components/MemoryTab';
@@ -25,6 +26,8 @@ import GpuTab from './components/GpuTab';
 import DiskTab from './components/DiskTab';
 import NetworkTab from './components/NetworkTab';
 import ProcessesTab from './components/ProcessesTab';
+import SessionControls from './components/SessionControls';
+import ExportMenu from './components/ExportMenu';
 // import SettingsTab from './components/SettingsTab';
 
 // No need to import App.css anymore if using index.css for directives
@@ -56,6 +59,7 @@ function App() {
   const { currentTab, darkMode } = useSelector((state) => state.ui);
   const { status: dataStatus, platform_name, timestamp, error: dataError } = useSelector((state) => state.systemData);
   const backendErrorMessage = useSelector((state) => state.error.backendErrorMessage);
+  const activeAlerts = useSelector((state) => state.alerts.active);
 
   // --- Theme Management (Using Tailwind 'class' strategy) ---
   useEffect(() => {
@@ -73,6 +77,8 @@ function App() {
   useEffect(() => {
     let unlistenSystemUpdate = () => {};
     let unlistenBackendError = () => {};
+    let unlistenAlertFired = () => {};
+    let unlistenAlertResolved = () => {};
     const setupTauriCommunication = async () => {
       try {
         dispatch(setLoading());
@@ -91,6 +97,9 @@ function App() {
           const msg = typeof event.payload === 'string' ? event.payload : 'Unknown backend error';
           dispatch(setBackendError(msg));
         });
+        unlistenAlertFired = await listen('alert-fired', (event) => dispatch(alertFired(event.payload)));
+        unlistenAlertResolved = await listen('alert-resolved', (event) => dispatch(alertResolved(event.payload)));
+        dispatch(setActiveAlerts(await invoke('get_active_alerts')));
       } catch (error) {
         const msg = `Tauri setup failed: ${error}`;
         dispatch(setDataFetchError(msg));
@@ -98,7 +107,7 @@ function App() {
       }
     };
     setupTauriCommunication();
-    return () => { unlistenSystemUpdate(); unlistenBackendError(); };
+    return () => { unlistenSystemUpdate(); unlistenBackendError(); unlistenAlertFired(); unlistenAlertResolved(); };
    }, [dispatch]);
 
   // --- Handlers (remain the same) ---
@@ -174,9 +183,25 @@ function App() {
 
              {/* System/App Controls */}
             <div className="flex items-center space-x-1">
+                <SessionControls />
+                <ExportMenu />
                 <div className="p-1.5 text-lg text-sci-accent-green animate-pulse cursor-help" title={`Last update: ${timestamp ? new Date(timestamp).toLocaleTimeString() : 'N/A'}`}>
                     {CONTROL_ICONS.Activity}
                 </div>
+                {activeAlerts.length > 0 && (
+                    <div
+                        className={clsx(
+                            "relative p-1.5 text-lg cursor-help",
+                            activeAlerts.some((alert) => alert.severity === 'critical') ? "text-sci-red" : "text-yellow-500"
+                        )}
+                        title={activeAlerts.map((alert) => `${alert.description || alert.rule_id}${alert.label ? ` [${alert.label}]` : ''}: ${alert.value.toFixed(1)}`).join('\n')}
+                    >
+                        {CONTROL_ICONS.Notification}
+                        <span className="absolute -top-0.5 -right-0.5 min-w-[14px] rounded-full bg-sci-red px-1 text-center text-[10px] leading-[14px] text-white">
+                            {activeAlerts.length}
+                        </span>
+                    </div>
+                )}
                 <button onClick={handleThemeToggle} title="Toggle Theme" className="p-1.5 rounded text-xl text-sci-text-light-secondary hover:bg-sci-bg-light hover:text-sci-text-light dark:text-sci-text-dark-secondary dark:hover:bg-sci-bg-dark-alt dark:hover:text-sci-text-dark">
                     {darkMode ? CONTROL_ICONS.ThemeLight : CONTROL_ICONS.ThemeDark}
                  </button>
@@ -4,12 +4,14 @@ import { configureStore } from '@reduxjs/toolkit';
 import systemDataReducer from '../features/systemData/systemDataSlice';
 import uiReducer from '../features/ui/uiSlice';
 import errorReducer from '../features/error/errorSlice';
+import alertsReducer from '../features/alerts/alertsSlice';
 
 export const store = configureStore({
   reducer: {
     systemData: systemDataReducer,
     ui: uiReducer,
     error: errorReducer,
+    alerts: alertsReducer,
   },
   // It's often safer to disable this check when dealing with data from Rust FFI,
   // unless you are certain all data structures (like timestamps) are serializable JSON types.
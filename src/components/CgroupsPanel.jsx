@@ -0,0 +1,93 @@
+import React, { useState } from 'react';
+import { useSelector } from 'react-redux';
+import clsx from 'clsx';
+import { getCpuColorClass, formatPercent, formatSpeed } from '../utils/formatting';
+
+// Short form used by docker/podman CLIs
+export const shortContainerId = (id) => id.slice(0, 12);
+
+const formatMB = (mb) => (mb >= 1024 ? `${(mb / 1024).toFixed(2)} GB` : `${mb.toFixed(0)} MB`);
+
+function CgroupsPanel() {
+  const { cgroups } = useSelector((state) => state.systemData);
+  const [containersOnly, setContainersOnly] = useState(false);
+  const [sortByCpu, setSortByCpu] = useState(false);
+
+  if (!cgroups || cgroups.length === 0) {
+    return (
+      <div className="p-4 text-sm text-sci-text-light-secondary dark:text-sci-text-dark-secondary">
+        No cgroup v2 hierarchy found.
+      </div>
+    );
+  }
+
+  // Hierarchy order from the backend (parents first); depth drives the indentation
+  let rows = cgroups.map((cgroup) => ({ ...cgroup, depth: cgroup.path === '/' ? 0 : cgroup.path.split('/').length - 1 }));
+  if (containersOnly) {
+    rows = rows.filter((c) => c.container && c.name.includes(c.container.id));
+  }
+  if (sortByCpu) {
+    rows = [...rows].sort((a, b) => b.cpu_usage - a.cpu_usage);
+  }
+
+  return (
+    <div>
+      <div className="px-4 py-2 flex gap-4 text-xs text-sci-text-light-secondary dark:text-sci-text-dark-secondary">
+        <label className="flex items-center gap-1.5 cursor-pointer">
+          <input type="checkbox" checked={containersOnly} onChange={(e) => setContainersOnly(e.target.checked)} />
+          Containers only
+        </label>
+        <label className="flex items-center gap-1.5 cursor-pointer">
+          <input type="checkbox" checked={sortByCpu} onChange={(e) => setSortByCpu(e.target.checked)} />
+          Sort by CPU
+        </label>
+      </div>
+      <div className="overflow-auto max-h-[600px]">
+        <table className="w-full">
+          <thead>
+            <tr className="border-b border-sci-border-light dark:border-sci-border-dark text-sm text-sci-text-light-secondary dark:text-sci-text-dark-secondary">
+              <th className="p-3 text-left font-medium">cgroup</th>
+              <th className="p-3 text-left font-medium">CPU</th>
+              <th className="p-3 text-left font-medium" title="memory.current / memory.max">Memory</th>
+              <th className="p-3 text-left font-medium">I/O read / write</th>
+              <th className="p-3 text-left font-medium" title="pids.current (includes threads)">Tasks</th>
+            </tr>
+          </thead>
+          <tbody>
+            {rows.map((cgroup) => (
+              <tr key={cgroup.path} className="border-b border-sci-border-light dark:border-sci-border-dark hover:bg-sci-bg-light hover:dark:bg-sci-bg-dark text-sm">
+                <td className="p-2 text-sci-text-light dark:text-sci-text-dark">
+                  <div
+                    className="flex items-center gap-2"
+                    style={{ paddingLeft: `${(containersOnly || sortByCpu ? 0 : cgroup.depth) * 14}px` }}
+                    title={cgroup.path}
+                  >
+                    <span className="truncate max-w-xs">{containersOnly || sortByCpu ? cgroup.path : cgroup.name}</span>
+                    {cgroup.container && cgroup.name.includes(cgroup.container.id) && (
+                      <span className="text-xs px-1.5 rounded bg-blue-100 text-blue-700 dark:bg-blue-900/40 dark:text-blue-300 font-mono">
+                        {cgroup.container.runtime} {shortContainerId(cgroup.container.id)}
+                      </span>
+                    )}
+                  </div>
+                </td>
+                <td className={clsx('p-2 font-mono', getCpuColorClass(cgroup.cpu_usage))}>{formatPercent(cgroup.cpu_usage)}</td>
+                <td className="p-2 font-mono text-sci-text-light dark:text-sci-text-dark">
+                  {cgroup.memory_current_mb != null ? formatMB(cgroup.memory_current_mb) : '—'}
+                  {cgroup.memory_max_mb != null && (
+                    <span className="text-sci-text-light-secondary dark:text-sci-text-dark-secondary"> / {formatMB(cgroup.memory_max_mb)}</span>
+                  )}
+                </td>
+                <td className="p-2 font-mono text-sci-text-light dark:text-sci-text-dark">
+                  {formatSpeed(cgroup.io_read_kbps)} / {formatSpeed(cgroup.io_write_kbps)}
+                </td>
+                <td className="p-2 font-mono text-sci-text-light dark:text-sci-text-dark">{cgroup.pids ?? '—'}</td>
+              </tr>
+            ))}
+          </tbody>
+        </table>
+      </div>
+    </div>
+  );
+}
+
+export default CgroupsPanel;
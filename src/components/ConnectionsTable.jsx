@@ -0,0 +1,113 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { LuRadar, LuRefreshCw, LuSearch } from "react-icons/lu";
+import { truncateText } from '../utils/formatting';
+
+// Brackets IPv6 addresses so the port stays readable
+const formatEndpoint = (address, port) => (address.includes(':') ? `[${address}]:${port}` : `${address}:${port}`);
+
+const stateColorClass = (state) => {
+  if (state === 'ESTABLISHED') return 'text-green-600 dark:text-green-400';
+  if (state === 'LISTEN') return 'text-blue-600 dark:text-blue-400';
+  if (state === 'UNCONN') return 'text-sci-text-light-secondary dark:text-sci-text-dark-secondary';
+  return 'text-yellow-600 dark:text-yellow-400';
+};
+
+function ConnectionsTable() {
+  const [connections, setConnections] = useState([]);
+  const [filter, setFilter] = useState('');
+  const [loading, setLoading] = useState(false);
+  const [error, setError] = useState(null);
+
+  // The scan walks every process's open files, so it runs on demand rather than every update
+  const refresh = useCallback(() => {
+    setLoading(true);
+    invoke('get_network_connections')
+      .then((result) => { setConnections(result); setError(null); })
+      .catch((e) => setError(`${e}`))
+      .finally(() => setLoading(false));
+  }, []);
+
+  useEffect(() => { refresh(); }, [refresh]);
+
+  // Counted from the last scan rather than every update
+  const countState = (state) => connections.filter((c) => c.protocol.startsWith('tcp') && c.state === state).length;
+  const udpCount = connections.filter((c) => c.protocol.startsWith('udp')).length;
+
+  const needle = filter.trim().toLowerCase();
+  const visible = needle
+    ? connections.filter((c) => [
+        c.protocol, c.state, c.process_name ?? '', `${c.pid ?? ''}`,
+        formatEndpoint(c.local_address, c.local_port), formatEndpoint(c.remote_address, c.remote_port),
+      ].some((field) => field.toLowerCase().includes(needle)))
+    : connections;
+
+  return (
+    <div className="rounded-md bg-sci-bg-light-alt dark:bg-sci-bg-dark-alt border border-sci-border-light dark:border-sci-border-dark shadow-sci-light dark:shadow-sci-dark">
+      <div className="p-4 border-b border-sci-border-light dark:border-sci-border-dark flex flex-wrap items-center justify-between gap-3">
+        <div className="flex items-center gap-2">
+          <LuRadar className="text-green-500 dark:text-green-400" />
+          <h3 className="text-base font-semibold text-sci-text-light dark:text-sci-text-dark">Connections</h3>
+          {connections.length > 0 && (
+            <span className="text-xs text-sci-text-light-secondary dark:text-sci-text-dark-secondary">
+              {countState('ESTABLISHED')} established · {countState('LISTEN')} listening · {udpCount} UDP
+            </span>
+          )}
+        </div>
+        <div className="flex items-center gap-2">
+          <div className="relative">
+            <LuSearch className="absolute left-2 top-1/2 -translate-y-1/2 w-3.5 h-3.5 text-sci-text-light-secondary dark:text-sci-text-dark-secondary" />
+            <input
+              type="text"
+              value={filter}
+              onChange={(e) => setFilter(e.target.value)}
+              placeholder="Filter address, state, process..."
+              className="pl-7 pr-2 py-1 text-xs rounded border border-sci-border-light dark:border-sci-border-dark bg-sci-bg-light dark:bg-sci-bg-dark text-sci-text-light dark:text-sci-text-dark"
+            />
+          </div>
+          <button onClick={refresh} disabled={loading} className="p-1.5 rounded opacity-70 hover:opacity-100" title="Refresh connections">
+            <LuRefreshCw className={loading ? 'w-4 h-4 animate-spin' : 'w-4 h-4'} />
+          </button>
+        </div>
+      </div>
+
+      {error ? (
+        <div className="p-4 text-sm text-red-500">Failed to load connections: {error}</div>
+      ) : (
+        <div className="overflow-auto max-h-96">
+          <table className="w-full">
+            <thead>
+              <tr className="border-b border-sci-border-light dark:border-sci-border-dark text-sm text-sci-text-light-secondary dark:text-sci-text-dark-secondary">
+                <th className="p-2 text-left font-medium">Proto</th>
+                <th className="p-2 text-left font-medium">Local</th>
+                <th className="p-2 text-left font-medium">Remote</th>
+                <th className="p-2 text-left font-medium">State</th>
+                <th className="p-2 text-left font-medium" title="Send / receive queue in bytes">Queues</th>
+                <th className="p-2 text-left font-medium">Process</th>
+              </tr>
+            </thead>
+            <tbody>
+              {visible.map((c) => (
+                <tr key={`${c.protocol}-${c.inode}-${c.local_port}-${c.remote_address}-${c.remote_port}`} className="border-b border-sci-border-light dark:border-sci-border-dark text-xs font-mono text-sci-text-light dark:text-sci-text-dark">
+                  <td className="p-2">{c.protocol}</td>
+                  <td className="p-2">{formatEndpoint(c.local_address, c.local_port)}</td>
+                  <td className="p-2">{c.remote_port === 0 ? '*' : formatEndpoint(c.remote_address, c.remote_port)}</td>
+                  <td className={`p-2 ${stateColorClass(c.state)}`}>{c.state}</td>
+                  <td className="p-2">{c.tx_queue} / {c.rx_queue}</td>
+                  <td className="p-2" title={c.pid ? undefined : 'Owner not visible (another user\'s process)'}>
+                    {c.pid ? `${truncateText(c.process_name || '?', 24)} (${c.pid})` : '—'}
+                  </td>
+                </tr>
+              ))}
+            </tbody>
+          </table>
+          {visible.length === 0 && !loading && (
+            <div className="p-4 text-center text-sm text-sci-text-light-secondary dark:text-sci-text-dark-secondary">No connections</div>
+          )}
+        </div>
+      )}
+    </div>
+  );
+}
+
+export default ConnectionsTable;
@@ -2,10 +2,10 @@ import React from 'react';
 import { useSelector } from 'react-redux';
 import { motion } from 'framer-motion';
 import clsx from 'clsx';
-import { LuCpu, LuActivity, LuClock } from "react-icons/lu";
+import { LuCpu, LuActivity, LuClock, LuThermometer } from "react-icons/lu";
 import { BiBarChartAlt } from "react-icons/bi";
 import { RiLayoutMasonryLine } from "react-icons/ri";
-import { getCpuColorClass, formatPercent } from '../utils/formatting';
+import { getCpuColorClass, formatPercent, formatDuration, buildHistoryTimestamps } from '../utils/formatting';
 import HistoricalChart from './HistoricalChart';
 
 // Animation variants for panels
@@ -22,8 +22,70 @@ const panelVariants = {
   })
 };
 
+// Formats a sensor value in its unit (°C, RPM, V, A)
+const formatSensorValue = (sensor) => {
+  switch (sensor.kind) {
+    case 'temperature': return `${sensor.value.toFixed(1)}°C`;
+    case 'fan': return `${sensor.value.toFixed(0)} RPM`;
+    case 'voltage': return `${sensor.value.toFixed(3)} V`;
+    case 'current': return `${sensor.value.toFixed(2)} A`;
+    default: return sensor.value.toFixed(2);
+  }
+};
+
+// Red at/above crit, amber at/above max
+const getSensorColorClass = (sensor) => {
+  if (sensor.crit != null && sensor.value >= sensor.crit) return 'text-red-500 dark:text-red-400';
+  if (sensor.max != null && sensor.value >= sensor.max) return 'text-yellow-500 dark:text-yellow-400';
+  return 'text-sci-text-light dark:text-sci-text-dark';
+};
+
+// /proc/stat categories shown in the stacked time chart (idle omitted)
+const CPU_TIME_CATEGORIES = [
+  { key: 'user', label: 'User', color: 'rgba(59, 130, 246, 1)' },      // blue-500
+  { key: 'nice', label: 'Nice', color: 'rgba(14, 165, 233, 1)' },      // sky-500
+  { key: 'system', label: 'System', color: 'rgba(239, 68, 68, 1)' },   // red-500
+  { key: 'iowait', label: 'I/O wait', color: 'rgba(245, 158, 11, 1)' }, // amber-500
+  { key: 'irq', label: 'IRQ', color: 'rgba(168, 85, 247, 1)' },        // purple-500
+  { key: 'softirq', label: 'SoftIRQ', color: 'rgba(217, 70, 239, 1)' }, // fuchsia-500
+  { key: 'steal', label: 'Steal', color: 'rgba(107, 114, 128, 1)' },   // gray-500
+  { key: 'guest', label: 'Guest', color: 'rgba(20, 184, 166, 1)' },    // teal-500
+];
+
 function CpuTab() {
-  const { cpu_usage, cpu_history, timestamp } = useSelector((state) => state.systemData);
+  const { cpu_usage, cpu_history, cpu_info, cpu_times, cpu_power, timestamp, cpu_history_timestamps, update_interval_ms, sensors } = useSelector((state) => state.systemData);
+
+  // Frequency scaling: average clock history and the governor/EPP in effect (usually the same on every core)
+  const frequencies = cpu_info?.frequencies ?? [];
+  const frequencyHistory = frequencies.length > 0
+    ? frequencies[0].history.map((_, i) => frequencies.reduce((sum, f) => sum + (f.history[i] ?? 0), 0) / frequencies.length)
+    : [];
+  const governors = [...new Set(frequencies.map((f) => f.governor).filter(Boolean))].join(', ');
+  const preferences = [...new Set(frequencies.map((f) => f.energy_performance_preference).filter(Boolean))].join(', ');
+  // Stacked time categories; idle is left out so the stack height is total busy time
+  const timeHistory = cpu_times?.history ?? [];
+  const timeSeries = CPU_TIME_CATEGORIES.map(({ key, label, color }) => ({
+    label, color, fillColor: color.replace(', 1)', ', 0.5)'), data: timeHistory.map((times) => times[key]),
+  }));
+  // RAPL package power; the chart shows the package total, sub-domains only as figures
+  const packageHistory = cpu_power?.package_history ?? [];
+  const formatWatts = (watts) => `${watts.toFixed(1)} W`;
+  const formatMHz = (mhz) => (mhz >= 1000 ? `${(mhz / 1000).toFixed(2)} GHz` : `${Math.round(mhz)} MHz`);
+  // Governor policy range, then what the hardware supports
+  const frequencyLimits = (freq) => [
+    freq.max_mhz && `Scaling: ${formatMHz(freq.min_mhz ?? 0)} – ${formatMHz(freq.max_mhz)}`,
+    freq.hardware_max_mhz && `Hardware: ${formatMHz(freq.hardware_min_mhz ?? 0)} – ${formatMHz(freq.hardware_max_mhz)}`,
+  ].filter(Boolean).join('\n') || undefined;
+
+  // Temperatures and fans per chip; thermal zones shown as one extra group
+  const sensorGroups = [
+    ...(sensors?.chips ?? []).map((chip) => ({
+      key: chip.id,
+      title: chip.name === chip.id ? chip.name : `${chip.name} (${chip.id})`,
+      sensors: chip.sensors.filter((sensor) => sensor.kind === 'temperature' || sensor.kind === 'fan'),
+    })),
+    { key: 'thermal_zones', title: 'Thermal zones', sensors: sensors?.thermal_zones ?? [] },
+  ].filter((group) => group.sensors.length > 0);
   
   // Calculate average CPU usage
   const avgCpuUsage = cpu_usage.length > 0
@@ -55,11 +117,8 @@ function CpuTab() {
     historyData = cpu_history.map(entry => entry.average);
   }
   
-  // Create timestamps array (since we don't have explicit timestamps in the array structure)
-  // Just use sequential numbers that will be formatted as time labels
-  const timestamps = Array.from({ length: historyData.length }, (_, i) => 
-    new Date(Date.now() - (historyData.length - i - 1) * 1000).toISOString()
-  );
+  // Real sample times from the backend (stay correct when the interval changes)
+  const timestamps = buildHistoryTimestamps(cpu_history_timestamps, historyData.length, update_interval_ms);
   
   // Get color for progress bar based on usage
   const getProgressColors = (usagePercent) => {
@@ -135,10 +194,31 @@ function CpuTab() {
               <div className="text-xs uppercase tracking-wider text-sci-text-light-secondary dark:text-sci-text-dark-secondary">
                 <div className="flex items-center gap-1.5 mb-1">
                   <LuClock className="h-3.5 w-3.5" />
-                  <span>Cores: {cpu_usage.length}</span>
+                  <span>
+                    Cores: {cpu_info?.physical_cores ? `${cpu_info.physical_cores} / ${cpu_usage.length} threads` : cpu_usage.length}
+                  </span>
                 </div>
               </div>
             </div>
+
+            {cpu_info?.model && (
+              <dl className="grid grid-cols-2 md:grid-cols-4 gap-x-4 gap-y-1 mb-4 text-xs">
+                {[
+                  ['Model', `${cpu_info.model}${cpu_info.vendor ? ` (${cpu_info.vendor})` : ''}`],
+                  ['Load (1/5/15m)', cpu_info.load_average.map((load) => load.toFixed(2)).join(' / ')],
+                  ['Uptime', formatDuration(cpu_info.uptime_secs)],
+                  ['Governor', [governors, preferences].filter(Boolean).join(' · ') || null],
+                  ['Power', cpu_power && packageHistory.length > 0
+                    ? [formatWatts(cpu_power.package_power_w), ...cpu_power.domains.filter((d) => d.name.includes('/')).map((d) => `${d.name.split('/')[1]} ${formatWatts(d.power_w)}`)].join(' · ')
+                    : null],
+                ].filter(([, value]) => value).map(([label, value]) => (
+                  <div key={label} className={clsx(label === 'Model' && "col-span-2 md:col-span-1")}>
+                    <dt className="text-sci-text-light-secondary dark:text-sci-text-dark-secondary">{label}</dt>
+                    <dd className="font-mono truncate text-sci-text-light dark:text-sci-text-dark" title={value}>{value}</dd>
+                  </div>
+                ))}
+              </dl>
+            )}
             
             {/* CPU Usage Bar */}
             <div className="mb-3">
@@ -158,6 +238,7 @@ function CpuTab() {
                 <HistoricalChart 
                   data={historyData}
                   timestamps={timestamps}
+                  historyMetric="cpu_usage_avg"
                   label="CPU Usage"
                   yAxisLabel="Usage (%)"
                   color="rgba(59, 130, 246, 1)" // blue-500
@@ -180,6 +261,53 @@ function CpuTab() {
                 />
               </div>
             )}
+            {timeHistory.length > 0 && (
+              <div className="mt-4">
+                <div className="flex flex-wrap gap-x-3 mb-1 text-xs text-sci-text-light-secondary dark:text-sci-text-dark-secondary">
+                  {CPU_TIME_CATEGORIES.filter(({ key }) => cpu_times.total[key] >= 0.05).map(({ key, label }) => (
+                    <span key={key}>{label} <span className="font-mono">{cpu_times.total[key].toFixed(1)}%</span></span>
+                  ))}
+                </div>
+                <div className="h-40">
+                  <HistoricalChart
+                    series={timeSeries}
+                    timestamps={buildHistoryTimestamps(cpu_times?.history_timestamps, timeHistory.length, update_interval_ms)}
+                    yAxisLabel="CPU time (%)"
+                    valueFormatter={(val) => `${val.toFixed(1)}%`}
+                    minY={0}
+                    maxY={100}
+                  />
+                </div>
+              </div>
+            )}
+            {packageHistory.length > 0 && (
+              <div className="mt-4 h-28">
+                <HistoricalChart
+                  data={packageHistory}
+                  timestamps={buildHistoryTimestamps(cpu_power?.package_history_timestamps, packageHistory.length, update_interval_ms)}
+                  label="Package Power"
+                  yAxisLabel="W"
+                  color="rgba(234, 179, 8, 1)" // yellow-500
+                  fillColor="rgba(234, 179, 8, 0.1)"
+                  valueFormatter={formatWatts}
+                  minY={0}
+                />
+              </div>
+            )}
+            {frequencyHistory.some((mhz) => mhz > 0) && (
+              <div className="mt-4 h-28">
+                <HistoricalChart
+                  data={frequencyHistory}
+                  timestamps={buildHistoryTimestamps(cpu_info?.frequency_history_timestamps, frequencyHistory.length, update_interval_ms)}
+                  label="Average Frequency"
+                  yAxisLabel="MHz"
+                  color="rgba(139, 92, 246, 1)" // violet-500
+                  fillColor="rgba(139, 92, 246, 0.1)"
+                  valueFormatter={formatMHz}
+                  minY={0}
+                />
+              </div>
+            )}
           </div>
         </motion.div>
         
@@ -210,13 +338,27 @@ function CpuTab() {
               {cpu_usage.map((usage, index) => {
                 const coreColors = getProgressColors(usage);
                 return (
-                  <div key={index} className="py-1">
+                  <div
+                    key={index}
+                    className="py-1"
+                    title={cpu_times?.per_core?.[index]
+                      ? CPU_TIME_CATEGORIES.map(({ key, label }) => `${label}: ${cpu_times.per_core[index][key].toFixed(1)}%`).join('\n')
+                      : undefined}
+                  >
                     <div className="flex justify-between items-center mb-1">
                       <div className="flex items-center gap-1.5">
                         <div className="h-2 w-2 bg-blue-500 dark:bg-blue-400 rounded-full"></div>
                         <span className="text-xs font-medium text-sci-text-light dark:text-sci-text-dark">
                           Core {index}
                         </span>
+                        {frequencies[index] && (
+                          <span
+                            className="text-xs font-mono text-sci-text-light-secondary dark:text-sci-text-dark-secondary"
+                            title={frequencyLimits(frequencies[index])}
+                          >
+                            {formatMHz(frequencies[index].current_mhz)}
+                          </span>
+                        )}
                       </div>
                       <span className={clsx(
                         "text-xs font-mono font-medium",
@@ -320,8 +462,60 @@ function CpuTab() {
           </div>
         </motion.div>
       )}
+
+      {/* Temperatures & Fans */}
+      {sensorGroups.length > 0 && (
+        <motion.div
+          className={clsx(
+            "overflow-hidden rounded-md",
+            "bg-sci-bg-light-alt dark:bg-sci-bg-dark-alt",
+            "border border-sci-border-light dark:border-sci-border-dark",
+            "shadow-sci-light dark:shadow-sci-dark"
+          )}
+          variants={panelVariants}
+          initial="hidden"
+          animate="visible"
+          custom={3}
+        >
+          <div className="p-4 border-b border-sci-border-light dark:border-sci-border-dark">
+            <div className="flex items-center gap-2">
+              <LuThermometer className="text-blue-500 dark:text-blue-400" />
+              <h3 className="text-base font-semibold text-sci-text-light dark:text-sci-text-dark">
+                Temperatures & Fans
+              </h3>
+            </div>
+          </div>
+
+          <div className="p-4 grid grid-cols-1 md:grid-cols-2 lg:grid-cols-3 gap-4">
+            {sensorGroups.map((group) => (
+              <div key={group.key} className={clsx(
+                "p-3 rounded-md",
+                "bg-sci-bg-light dark:bg-sci-bg-dark",
+                "border border-sci-border-light dark:border-sci-border-dark"
+              )}>
+                <div className="text-xs font-medium mb-2 text-sci-text-light-secondary dark:text-sci-text-dark-secondary">
+                  {group.title}
+                </div>
+                <div className="space-y-1">
+                  {group.sensors.map((sensor) => (
+                    <div key={sensor.id} className="flex items-center justify-between text-xs">
+                      <span className="truncate text-sci-text-light dark:text-sci-text-dark" title={sensor.id}>{sensor.label}</span>
+                      <span
+                        className={clsx("font-mono", getSensorColorClass(sensor))}
+                        title={[sensor.max != null && `max ${sensor.max}`, sensor.crit != null && `crit ${sensor.crit}`].filter(Boolean).join(', ')}
+                      >
+                        {formatSensorValue(sensor)}
+                      </span>
+                    </div>
+                  ))}
+                </div>
+              </div>
+            ))}
+          </div>
+        </motion.div>
+      )}
     </div>
   );
 }
 
-export default CpuTab;
\ No newline at end of file
+export default CpuTab;
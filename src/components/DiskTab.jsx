@@ -3,7 +3,7 @@ import { useSelector } from 'react-redux';
 import { motion } from 'framer-motion';
 import clsx from 'clsx';
 import { LuDisc3, LuActivity, LuHardDrive, LuServer, LuFolder, LuArchive, LuFilter } from "react-icons/lu";
-import { getMemoryColorClass, formatBytes } from '../utils/formatting';
+import { getMemoryColorClass, formatBytes, buildHistoryTimestamps } from '../utils/formatting';
 import HistoricalChart from './HistoricalChart';
 
 // Animation variants for panels
@@ -21,13 +21,9 @@ const panelVariants = {
 };
 
 function DiskTab() {
-  const { disk_data } = useSelector((state) => state.systemData);
+  const { disk_data, update_interval_ms } = useSelector((state) => state.systemData);
   const disks = Object.entries(disk_data || {});
   
-  // Create timestamps array for history visualizations
-  const timestamps = Array.from({ length: 100 }, (_, i) => 
-    new Date(Date.now() - (100 - i - 1) * 1000).toISOString()
-  );
   
   // Helper function to validate numeric values
   const isValidNumber = (value) => {
@@ -52,12 +48,12 @@ function DiskTab() {
   };
   
   // Helper function to safely format byte rates
-  const safeFormatBytesPerSec = (bytesPerSec) => {
-    if (!isValidNumber(bytesPerSec)) {
+  const safeFormatKbPerSec = (kbPerSec) => {
+    if (!isValidNumber(kbPerSec)) {
       return 'N/A';
     }
     // Multiply by 1024 to convert KB/s to bytes/s for formatBytes function
-    return formatBytes(bytesPerSec * 1024) + '/s';
+    return formatBytes(kbPerSec * 1024) + '/s';
   };
   
   // Helper function to filter invalid data points from history arrays
@@ -278,6 +274,8 @@ function DiskTab() {
     // Clean and validate history data
     const cleanReadHistory = filterValidData(disk.read_history);
     const cleanWriteHistory = filterValidData(disk.write_history);
+    // The disk's own sample times, aligned to the end of each series
+    const timestamps = buildHistoryTimestamps(disk.history_timestamps, disk.read_history?.length ?? 0, update_interval_ms);
     
     // Check if read/write history exists and has valid data
     const hasReadHistory = cleanReadHistory.length > 0;
@@ -376,7 +374,7 @@ function DiskTab() {
                 </div>
               </div>
               <div className="font-mono text-lg font-medium text-blue-600 dark:text-blue-400">
-                {safeFormatBytesPerSec(disk.read_bytes_per_sec)}
+                {safeFormatKbPerSec(disk.read_kb_per_sec)}
               </div>
             </div>
             <div className={clsx(
@@ -391,7 +389,7 @@ function DiskTab() {
                 </div>
               </div>
               <div className="font-mono text-lg font-medium text-amber-600 dark:text-amber-400">
-                {safeFormatBytesPerSec(disk.write_bytes_per_sec)}
+                {safeFormatKbPerSec(disk.write_kb_per_sec)}
               </div>
             </div>
           </div>
@@ -425,6 +423,8 @@ function DiskTab() {
                     <HistoricalChart 
                       data={cleanReadHistory}
                       timestamps={timestamps.slice(-cleanReadHistory.length)}
+                      historyMetric="disk_read_kbps"
+                      historyLabel={name}
                       label="Read Speed"
                       yAxisLabel="KB/s"
                       color="rgba(59, 130, 246, 1)" // Tailwind blue-500
@@ -465,6 +465,8 @@ function DiskTab() {
                     <HistoricalChart 
                       data={cleanWriteHistory}
                       timestamps={timestamps.slice(-cleanWriteHistory.length)}
+                      historyMetric="disk_write_kbps"
+                      historyLabel={name}
                       label="Write Speed"
                       yAxisLabel="KB/s"
                       color="rgba(245, 158, 11, 1)" // Tailwind amber-500
@@ -0,0 +1,84 @@
+import React, { useState, useCallback } from 'react';
+import { useDispatch } from 'react-redux';
+import { invoke } from '@tauri-apps/api/core';
+import clsx from 'clsx';
+import { LuArrowDown, LuChartLine, LuList } from "react-icons/lu";
+import { setBackendError } from '../features/error/errorSlice';
+
+const FORMATS = ['csv', 'json'];
+
+const buttonClass = "p-1.5 rounded text-xl text-sci-text-light-secondary hover:bg-sci-bg-light hover:text-sci-text-light dark:text-sci-text-dark-secondary dark:hover:bg-sci-bg-dark-alt dark:hover:text-sci-text-dark";
+
+// Writes the latest snapshot or one of its histories to a file (Downloads by default)
+function ExportMenu() {
+  const dispatch = useDispatch();
+  const [menuOpen, setMenuOpen] = useState(false);
+  const [series, setSeries] = useState([]);
+  const [format, setFormat] = useState('csv');
+  const [savedPath, setSavedPath] = useState(null);
+
+  const reportError = useCallback((e) => dispatch(setBackendError(`${e}`)), [dispatch]);
+
+  const toggleMenu = () => {
+    if (!menuOpen) {
+      setSavedPath(null);
+      invoke('list_export_series').then(setSeries).catch(reportError);
+    }
+    setMenuOpen(!menuOpen);
+  };
+
+  const runExport = (command, args) => {
+    invoke(command, { ...args, format }).then(setSavedPath).catch(reportError);
+  };
+
+  const itemClass = "w-full px-3 py-1.5 flex items-center gap-2 text-left hover:bg-sci-bg-light hover:dark:bg-sci-bg-dark";
+
+  return (
+    <div className="relative">
+      <button onClick={toggleMenu} title="Export data" className={buttonClass}>
+        <LuArrowDown />
+      </button>
+
+      {menuOpen && (
+        <div className="absolute right-0 top-full mt-2 w-80 z-50 rounded-md shadow-lg border border-sci-border-light dark:border-sci-border-dark bg-sci-bg-light-alt dark:bg-sci-bg-dark-alt text-sm">
+          <div className="px-3 py-2 border-b border-sci-border-light dark:border-sci-border-dark flex items-center justify-between text-xs text-sci-text-light-secondary dark:text-sci-text-dark-secondary">
+            <span>Export</span>
+            <div className="flex rounded border border-sci-border-light dark:border-sci-border-dark overflow-hidden">
+              {FORMATS.map((f) => (
+                <button
+                  key={f}
+                  onClick={() => setFormat(f)}
+                  className={clsx("px-2 py-0.5 uppercase", format === f ? "bg-sci-accent-blue text-white" : "hover:bg-sci-bg-light hover:dark:bg-sci-bg-dark")}
+                >
+                  {f}
+                </button>
+              ))}
+            </div>
+          </div>
+
+          <div className="max-h-80 overflow-auto py-1 text-sci-text-light dark:text-sci-text-dark">
+            <button onClick={() => runExport('export_snapshot', {})} className={itemClass}>
+              <LuList className="shrink-0 text-sci-text-light-secondary dark:text-sci-text-dark-secondary" />
+              <span className="flex-grow">Current snapshot</span>
+            </button>
+            {series.map((s) => (
+              <button key={s.id} onClick={() => runExport('export_history', { series: s.id })} title={s.columns.join(', ')} className={itemClass}>
+                <LuChartLine className="shrink-0 text-sci-text-light-secondary dark:text-sci-text-dark-secondary" />
+                <span className="flex-grow truncate">{s.title}</span>
+                <span className="text-xs font-mono text-sci-text-light-secondary dark:text-sci-text-dark-secondary">{s.samples}</span>
+              </button>
+            ))}
+          </div>
+
+          {savedPath && (
+            <div className="px-3 py-2 border-t border-sci-border-light dark:border-sci-border-dark text-xs text-green-600 dark:text-green-400 break-all">
+              Saved to {savedPath}
+            </div>
+          )}
+        </div>
+      )}
+    </div>
+  );
+}
+
+export default ExportMenu;
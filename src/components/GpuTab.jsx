@@ -4,7 +4,7 @@ import { motion } from 'framer-motion';
 import clsx from 'clsx';
 import { BsGpuCard } from "react-icons/bs";
 import { LuActivity, LuThermometer, LuZap, LuDatabase, LuChartLine, LuCpu } from "react-icons/lu";
-import { getCpuColorClass, getTemperatureColorClass } from '../utils/formatting';
+import { getCpuColorClass, getTemperatureColorClass, buildHistoryTimestamps } from '../utils/formatting';
 import HistoricalChart from './HistoricalChart';
 
 // Animation variants for panels
@@ -22,15 +22,11 @@ const panelVariants = {
 };
 
 function GpuTab() {
-  const { gpu_data } = useSelector((state) => state.systemData);
+  const { gpu_data, update_interval_ms } = useSelector((state) => state.systemData);
 
   // Check if we have GPU data
   const hasGpuData = Array.isArray(gpu_data) && gpu_data.length > 0;
 
-  // Create timestamps array (since we don't have explicit timestamps)
-  const timestamps = Array.from({ length: 100 }, (_, i) => 
-    new Date(Date.now() - (100 - i - 1) * 1000).toISOString()
-  );
   
   // Get color for progress bar based on usage
   const getProgressColors = (usagePercent) => {
@@ -280,7 +276,9 @@ function GpuTab() {
                     <div className="h-40">
                       <HistoricalChart 
                         data={gpu.utilization_history.filter(val => val !== undefined)}
-                        timestamps={timestamps.slice(-gpu.utilization_history.length)}
+                        timestamps={buildHistoryTimestamps(gpu.history_timestamps, gpu.utilization_history.length, update_interval_ms)}
+                        historyMetric="gpu_utilization"
+                        historyLabel={String(index)}
                         label="GPU Usage"
                         yAxisLabel="Usage (%)"
                         color="rgba(20, 184, 166, 1)" // teal-500
@@ -1,6 +1,8 @@
 // src/components/HistoricalChart.jsx - With Options Fix
 
-import React from 'react';
+import React, { useState, useEffect } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import clsx from 'clsx';
 import { Line } from 'react-chartjs-2';
 import {
   Chart as ChartJS, CategoryScale, LinearScale, PointElement,
@@ -12,22 +14,79 @@ ChartJS.register(
   CategoryScale, LinearScale, PointElement, LineElement, Title, Tooltip, Legend, Filler
 );
 
+// Ranges offered when the chart is backed by a persisted metric; 'live' is the in-memory history
+const TIME_RANGES = [
+  { key: 'live', label: 'Live' },
+  { key: '1h', label: '1h', ms: 60 * 60 * 1000 },
+  { key: '6h', label: '6h', ms: 6 * 60 * 60 * 1000 },
+  { key: '24h', label: '24h', ms: 24 * 60 * 60 * 1000 },
+  { key: '7d', label: '7d', ms: 7 * 24 * 60 * 60 * 1000 },
+];
+const STORED_MAX_POINTS = 300;
+const STORED_REFRESH_MS = 60 * 1000;
+
+// Stored ranges span hours or days, so label them with the date as well
+const formatStoredTime = (ts) => new Date(ts).toLocaleString(undefined, {
+  month: 'short', day: 'numeric', hour: '2-digit', minute: '2-digit', hour12: false
+});
+
+// Loads `metric`/`label` from the history store for the selected range (null while live)
+function useStoredHistory(metric, label, range) {
+  const [stored, setStored] = useState(null);
+
+  useEffect(() => {
+    const selected = TIME_RANGES.find(r => r.key === range);
+    if (!metric || !selected?.ms) {
+      setStored(null);
+      return undefined;
+    }
+    let cancelled = false;
+    const load = () => {
+      const endMs = Date.now();
+      invoke('query_metric_history', { metric, label, startMs: endMs - selected.ms, endMs, maxPoints: STORED_MAX_POINTS })
+        .then((series) => {
+          if (!cancelled) setStored({ timestamps: series[0]?.timestamps ?? [], values: series[0]?.values ?? [] });
+        })
+        .catch((e) => { if (!cancelled) setStored({ timestamps: [], values: [], error: `${e}` }); });
+    };
+    load();
+    const timer = setInterval(load, STORED_REFRESH_MS);
+    return () => { cancelled = true; clearInterval(timer); };
+  }, [metric, label, range]);
+
+  return stored;
+}
+
 function HistoricalChart({
   data = [], timestamps = [], label = 'Usage',
   color = 'rgba(75, 192, 192, 1)', fillColor = 'rgba(75, 192, 192, 0.2)',
   showFill = true, minY = 0, maxY, yAxisLabel = 'Value',
   valueFormatter = (val) => val?.toString() ?? 'N/A',
+  series, // Optional [{ label, data, color, fillColor }]: drawn as stacked areas instead of `data`
+  stacked = true, // With `series`: false draws them as overlapping lines
+  historyMetric, // Optional persisted metric (e.g. "cpu_usage_avg"): adds a time range selector
+  historyLabel = '', // Label of that metric's series ('' for single-series metrics)
   options: incomingOptions = {}
 }) {
+  const [range, setRange] = useState('live');
+  const stored = useStoredHistory(historyMetric, historyLabel, range);
+  const showStored = !!stored && !series;
+  const values = showStored ? stored.values : data;
 
-  const labels = timestamps.map(ts => formatTimeForChart(ts));
+  const labels = showStored
+    ? stored.timestamps.map(formatStoredTime)
+    : timestamps.map(ts => formatTimeForChart(ts));
   const chartData = {
     labels: labels,
-    datasets: [{
-        label: label, data: data, borderColor: color, backgroundColor: fillColor,
+    datasets: series ? series.map((s, index) => ({
+        label: s.label, data: s.data, borderColor: s.color, backgroundColor: s.fillColor ?? s.color,
+        borderWidth: 1, pointRadius: 0, pointHoverRadius: 3, tension: 0.3, fill: stacked ? (index === 0 ? 'origin' : '-1') : false,
+      })) : [{
+        label: label, data: values, borderColor: color, backgroundColor: fillColor,
         borderWidth: 1.5, pointRadius: 0, pointHoverRadius: 3, tension: 0.3, fill: showFill,
       }],
   };
+  const pointCount = series ? (series[0]?.data?.length ?? 0) : (values?.length ?? 0);
 
   const defaultOptions = {
     responsive: true, maintainAspectRatio: false, animation: { duration: 250 },
@@ -36,6 +95,7 @@ function HistoricalChart({
         min: (typeof minY === 'number' && !isNaN(minY)) ? minY : undefined,
         max: (typeof maxY === 'number' && !isNaN(maxY)) ? maxY : undefined,
         display: true, // Default show
+        stacked: !!series && stacked,
         grid: { color: 'rgba(125, 140, 154, 0.1)', drawBorder: false },
         ticks: { maxTicksLimit: 5, font: { size: 9 }, color: '#7d8c9a', callback: valueFormatter },
         title: { display: !!yAxisLabel, text: yAxisLabel, font: { size: 10 }, color: '#7d8c9a' }
@@ -47,7 +107,7 @@ function HistoricalChart({
       }
     },
     plugins: {
-      legend: { display: false },
+      legend: { display: !!series, position: 'bottom', labels: { boxWidth: 8, font: { size: 9 }, color: '#7d8c9a' } },
       tooltip: {
         enabled: true, backgroundColor: 'rgba(10, 15, 20, 0.8)', titleFont: { size: 10 },
         bodyFont: { size: 10 }, padding: 6, boxPadding: 4,
@@ -76,16 +136,44 @@ function HistoricalChart({
 
   const finalOptions = mergeOptions(JSON.parse(JSON.stringify(defaultOptions)), incomingOptions);
 
+  const chart = pointCount > 0 ? (
+    <Line data={chartData} options={finalOptions} />
+  ) : (
+    // Use Tailwind for placeholder styling
+    <div className="flex items-center justify-center h-full text-xs italic text-sci-text-light-secondary dark:text-sci-text-dark-secondary opacity-50">
+        {stored?.error ?? 'No historical data'}
+    </div>
+  );
+
+  if (!historyMetric || series) {
+    return (
+      <div className="relative h-full w-full"> {/* Use Tailwind classes here if needed */}
+        {chart}
+      </div>
+    );
+  }
+
   return (
-    <div className="relative h-full w-full"> {/* Use Tailwind classes here if needed */}
-      {(data?.length ?? 0) > 0 ? (
-        <Line data={chartData} options={finalOptions} />
-      ) : (
-        // Use Tailwind for placeholder styling
-        <div className="flex items-center justify-center h-full text-xs italic text-sci-text-light-secondary dark:text-sci-text-dark-secondary opacity-50">
-            No historical data
-        </div>
-      )}
+    <div className="relative h-full w-full flex flex-col">
+      <div className="flex justify-end gap-0.5 mb-1">
+        {TIME_RANGES.map((r) => (
+          <button
+            key={r.key}
+            onClick={() => setRange(r.key)}
+            className={clsx(
+              "px-1.5 py-0.5 rounded text-[10px] leading-none",
+              range === r.key
+                ? "bg-sci-accent-blue text-white"
+                : "text-sci-text-light-secondary dark:text-sci-text-dark-secondary hover:bg-sci-bg-light hover:dark:bg-sci-bg-dark"
+            )}
+          >
+            {r.label}
+          </button>
+        ))}
+      </div>
+      <div className="relative flex-grow min-h-0">
+        {chart}
+      </div>
     </div>
   );
 }
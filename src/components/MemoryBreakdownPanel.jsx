@@ -0,0 +1,142 @@
+import React, { useState } from 'react';
+import { useSelector } from 'react-redux';
+import clsx from 'clsx';
+import { LuMemoryStick } from "react-icons/lu";
+import { buildHistoryTimestamps } from '../utils/formatting';
+import HistoricalChart from './HistoricalChart';
+
+// Segments of the stacked bar; together with "other kernel" they add up to MemTotal.
+// Shmem is part of Cached, so page cache is shown without it.
+const SEGMENTS = [
+  { key: 'anonymous', label: 'Applications', color: 'bg-pink-500', hint: 'Process heaps and stacks (AnonPages)' },
+  { key: 'shared', label: 'Shared / tmpfs', color: 'bg-purple-500', hint: 'Shmem: tmpfs and shared memory, not reclaimable' },
+  { key: 'page_cache', label: 'Page cache', color: 'bg-blue-500', hint: 'Cached minus Shmem, dropped under pressure' },
+  { key: 'buffers', label: 'Buffers', color: 'bg-cyan-500', hint: 'Block device metadata' },
+  { key: 'slab_reclaimable', label: 'Slab (reclaimable)', color: 'bg-teal-500', hint: 'SReclaimable: dentry/inode caches' },
+  { key: 'slab_unreclaimable', label: 'Slab (unreclaimable)', color: 'bg-amber-500', hint: 'SUnreclaim: kernel allocations' },
+  { key: 'free', label: 'Free', color: 'bg-gray-300 dark:bg-gray-600', hint: 'MemFree: completely unused' },
+];
+
+const formatGB = (value) => (value >= 1 ? `${value.toFixed(2)} GB` : `${(value * 1024).toFixed(0)} MB`);
+
+function MemoryBreakdownPanel() {
+  const { memory_details: details, update_interval_ms } = useSelector((state) => state.systemData);
+  const [selected, setSelected] = useState('available');
+
+  if (!details || details.total <= 0) return null;
+
+  const values = {
+    anonymous: details.anonymous.value,
+    shared: details.shared.value,
+    page_cache: Math.max(details.cached.value - details.shared.value, 0),
+    buffers: details.buffers.value,
+    slab_reclaimable: details.slab_reclaimable.value,
+    slab_unreclaimable: details.slab_unreclaimable.value,
+    free: details.free.value,
+  };
+  const accounted = Object.values(values).reduce((sum, v) => sum + v, 0);
+  const otherKernel = Math.max(details.total - accounted, 0); // Page tables, kernel stacks, vmalloc, hugepages, ...
+
+  const rows = [
+    ['available', 'Available'],
+    ['free', 'Free'],
+    ['anonymous', 'Applications'],
+    ['cached', 'Page cache (incl. shared)'],
+    ['shared', 'Shared / tmpfs'],
+    ['buffers', 'Buffers'],
+    ['slab_reclaimable', 'Slab reclaimable'],
+    ['slab_unreclaimable', 'Slab unreclaimable'],
+    ['dirty', 'Dirty'],
+    ['writeback', 'Writeback'],
+    ['committed', 'Committed'],
+    ...(details.hugepages_total > 0 ? [['hugepages_used', 'Huge pages used']] : []),
+  ];
+  const selectedHistory = details[selected]?.history ?? [];
+  const commitPercent = details.commit_limit > 0 ? (details.committed.value / details.commit_limit) * 100 : 0;
+
+  return (
+    <div className="rounded-md bg-sci-bg-light-alt dark:bg-sci-bg-dark-alt border border-sci-border-light dark:border-sci-border-dark shadow-sci-light dark:shadow-sci-dark">
+      <div className="p-4 border-b border-sci-border-light dark:border-sci-border-dark flex items-center gap-2">
+        <LuMemoryStick className="text-pink-500 dark:text-pink-400" />
+        <h3 className="text-base font-semibold text-sci-text-light dark:text-sci-text-dark">Memory Breakdown</h3>
+      </div>
+
+      <div className="p-4 space-y-4">
+        {/* Where MemTotal goes */}
+        <div>
+          <div className="flex h-4 w-full overflow-hidden rounded">
+            {SEGMENTS.map(({ key, label, color }) => (
+              <div
+                key={key}
+                className={color}
+                style={{ width: `${(values[key] / details.total) * 100}%` }}
+                title={`${label}: ${formatGB(values[key])}`}
+              />
+            ))}
+            <div className="bg-gray-500" style={{ width: `${(otherKernel / details.total) * 100}%` }} title={`Other kernel: ${formatGB(otherKernel)}`} />
+          </div>
+          <div className="mt-2 flex flex-wrap gap-x-4 gap-y-1 text-xs text-sci-text-light-secondary dark:text-sci-text-dark-secondary">
+            {SEGMENTS.map(({ key, label, color, hint }) => (
+              <span key={key} className="flex items-center gap-1" title={hint}>
+                <span className={clsx('inline-block w-2 h-2 rounded-sm', color)} />
+                {label} {formatGB(values[key])}
+              </span>
+            ))}
+            <span className="flex items-center gap-1" title="Page tables, kernel stacks, vmalloc, huge pages, ...">
+              <span className="inline-block w-2 h-2 rounded-sm bg-gray-500" />
+              Other kernel {formatGB(otherKernel)}
+            </span>
+          </div>
+        </div>
+
+        <div className="grid grid-cols-1 lg:grid-cols-2 gap-4">
+          {/* Every field; click one to chart its history */}
+          <div className="text-xs">
+            {rows.map(([key, label]) => (
+              <button
+                key={key}
+                onClick={() => setSelected(key)}
+                className={clsx(
+                  'w-full flex justify-between px-2 py-1 rounded',
+                  selected === key ? 'bg-pink-100 dark:bg-pink-900/30' : 'hover:bg-sci-bg-light hover:dark:bg-sci-bg-dark'
+                )}
+              >
+                <span className="text-sci-text-light-secondary dark:text-sci-text-dark-secondary">{label}</span>
+                <span className="font-mono text-sci-text-light dark:text-sci-text-dark">{formatGB(details[key].value)}</span>
+              </button>
+            ))}
+            <div className="mt-2 px-2" title="Committed_AS against CommitLimit; only enforced with vm.overcommit_memory=2">
+              <div className="flex justify-between text-sci-text-light-secondary dark:text-sci-text-dark-secondary">
+                <span>Commit charge</span>
+                <span className="font-mono">{formatGB(details.committed.value)} / {formatGB(details.commit_limit)}</span>
+              </div>
+              <div className="mt-1 h-1.5 w-full rounded bg-gray-200 dark:bg-gray-700 overflow-hidden">
+                <div
+                  className={clsx('h-full', commitPercent >= 100 ? 'bg-red-500' : 'bg-pink-500')}
+                  style={{ width: `${Math.min(commitPercent, 100)}%` }}
+                />
+              </div>
+            </div>
+          </div>
+
+          <div className="h-56">
+            {selectedHistory.length > 0 && (
+              <HistoricalChart
+                data={selectedHistory}
+                timestamps={buildHistoryTimestamps(details.history_timestamps, selectedHistory.length, update_interval_ms)}
+                label={rows.find(([key]) => key === selected)?.[1] ?? selected}
+                yAxisLabel="GB"
+                color="rgba(236, 72, 153, 1)" // pink-500
+                fillColor="rgba(236, 72, 153, 0.15)"
+                valueFormatter={formatGB}
+                minY={0}
+              />
+            )}
+          </div>
+        </div>
+      </div>
+    </div>
+  );
+}
+
+export default MemoryBreakdownPanel;
@@ -6,8 +6,9 @@ import { LuMemoryStick, LuActivity, LuHardDrive } from "react-icons/lu";
 import { BiBarChartAlt } from "react-icons/bi";
 import { GrPieChart } from "react-icons/gr";
 
-import { getMemoryColorClass, formatBytes } from '../utils/formatting';
+import { getMemoryColorClass, formatBytes, buildHistoryTimestamps } from '../utils/formatting';
 import HistoricalChart from './HistoricalChart';
+import MemoryBreakdownPanel from './MemoryBreakdownPanel';
 
 // Animation variants for panels
 const panelVariants = {
@@ -24,7 +25,7 @@ const panelVariants = {
 };
 
 function MemoryTab() {
-  const { memory_used, memory_total, memory_history } = useSelector((state) => state.systemData);
+  const { memory_used, memory_total, memory_history, memory_history_timestamps, update_interval_ms } = useSelector((state) => state.systemData);
   
   // Calculate memory usage percentage
   const memoryUsagePercent = memory_total > 0
@@ -49,10 +50,8 @@ function MemoryTab() {
     // Direct percentage values
     memoryPercentHistory = memory_history;
     
-    // Create timestamps array (since we don't have explicit timestamps)
-    timestamps = Array.from({ length: memoryPercentHistory.length }, (_, i) => 
-      new Date(Date.now() - (memoryPercentHistory.length - i - 1) * 1000).toISOString()
-    );
+    // Real sample times from the backend (stay correct when the interval changes)
+    timestamps = buildHistoryTimestamps(memory_history_timestamps, memoryPercentHistory.length, update_interval_ms);
   } else {
     // Original implementation for object-based history
     memoryPercentHistory = memory_history.map(entry => 
@@ -178,6 +177,7 @@ function MemoryTab() {
                 <HistoricalChart 
                   data={memoryPercentHistory}
                   timestamps={timestamps}
+                  historyMetric="memory_percent"
                   label="Memory Usage"
                   yAxisLabel="Usage (%)"
                   color={memoryBaseColor}
@@ -406,6 +406,9 @@ function MemoryTab() {
           )}
         </div>
       </motion.div>
+
+      {/* /proc/meminfo breakdown (Linux only) */}
+      <MemoryBreakdownPanel />
     </div>
   );
 }
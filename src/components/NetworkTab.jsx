@@ -4,8 +4,9 @@ import { motion } from 'framer-motion';
 import clsx from 'clsx';
 import { LuNetwork, LuActivity, LuArrowDown, LuArrowUp, LuRefreshCw, LuWifi, LuChartBar, LuRadar } from "react-icons/lu";
 import { BsArrowDownCircle, BsArrowUpCircle } from "react-icons/bs";
-import { formatSpeed, formatBytes } from '../utils/formatting';
+import { formatSpeed, formatBytes, buildHistoryTimestamps } from '../utils/formatting';
 import HistoricalChart from './HistoricalChart';
+import ConnectionsTable from './ConnectionsTable';
 
 // Animation variants for panels
 const panelVariants = {
@@ -22,17 +23,15 @@ const panelVariants = {
 };
 
 function NetworkTab() {
-  const { network_data } = useSelector((state) => state.systemData);
+  const { network_data, update_interval_ms } = useSelector((state) => state.systemData);
   const interfaces = Object.entries(network_data || {});
   
   // Calculate totals
   const totalRx = interfaces.reduce((sum, [_, data]) => sum + data.current_rx_speed, 0);
   const totalTx = interfaces.reduce((sum, [_, data]) => sum + data.current_tx_speed, 0);
   
-  // Create timestamps array for history visualizations
-  const timestamps = Array.from({ length: 100 }, (_, i) => 
-    new Date(Date.now() - (100 - i - 1) * 1000).toISOString()
-  );
+  // Chart timestamps from the interface's own sample times
+  const timestampsFor = (data, length) => buildHistoryTimestamps(data.history_timestamps, length, update_interval_ms);
   
   // Colors for network visualization
   const downloadColor = 'rgba(52, 152, 219, 1)'; // blue
@@ -182,7 +181,7 @@ function NetworkTab() {
                       <div className="h-32">
                         <HistoricalChart 
                           data={interfaces[0][1].rx_history}
-                          timestamps={timestamps.slice(-interfaces[0][1].rx_history.length)}
+                          timestamps={timestampsFor(interfaces[0][1], interfaces[0][1].rx_history.length)}
                           label="Download"
                           yAxisLabel="KB/s"
                           color={downloadColor}
@@ -220,7 +219,7 @@ function NetworkTab() {
                       <div className="h-32">
                         <HistoricalChart 
                           data={interfaces[0][1].tx_history}
-                          timestamps={timestamps.slice(-interfaces[0][1].tx_history.length)}
+                          timestamps={timestampsFor(interfaces[0][1], interfaces[0][1].tx_history.length)}
                           label="Upload"
                           yAxisLabel="KB/s"
                           color={uploadColor}
@@ -286,6 +285,16 @@ function NetworkTab() {
                           <h3 className="text-base font-semibold text-sci-text-light dark:text-sci-text-dark truncate">
                             {name}
                           </h3>
+                          {data.info?.operstate && (
+                            <span className={clsx(
+                              "text-xs px-1.5 py-0.5 rounded",
+                              data.info.operstate === 'up'
+                                ? "bg-green-100 text-green-700 dark:bg-green-900/40 dark:text-green-400"
+                                : "bg-gray-100 text-gray-600 dark:bg-gray-800 dark:text-gray-400"
+                            )}>
+                              {data.info.operstate}
+                            </span>
+                          )}
                         </div>
                         <div className={clsx(
                           "w-3 h-3 rounded-full transition-all duration-300",
@@ -331,6 +340,57 @@ function NetworkTab() {
                       </div>
                     </div>
                     
+                    {/* Link Details & Counters */}
+                    {data.info && (
+                      <div className="p-4 border-b border-sci-border-light dark:border-sci-border-dark space-y-3">
+                        <dl className="grid grid-cols-2 gap-x-4 gap-y-1 text-xs">
+                          {[
+                            ['Addresses', [...data.info.ipv4_addresses, ...data.info.ipv6_addresses].join(', ')],
+                            ['MAC', data.info.mac_address],
+                            ['MTU', data.info.mtu],
+                            ['Link', data.info.speed_mbps ? `${data.info.speed_mbps} Mb/s${data.info.duplex ? ` ${data.info.duplex} duplex` : ''}` : null],
+                            ['Driver', data.info.driver],
+                          ].filter(([, value]) => value !== null && value !== undefined && value !== '').map(([label, value]) => (
+                            <div key={label} className={clsx("flex justify-between gap-3", label === 'Addresses' && "col-span-2")}>
+                              <dt className="text-sci-text-light-secondary dark:text-sci-text-dark-secondary">{label}</dt>
+                              <dd className="font-mono text-right break-all text-sci-text-light dark:text-sci-text-dark">{value}</dd>
+                            </div>
+                          ))}
+                        </dl>
+                        {data.counters && (
+                          <div className="grid grid-cols-4 gap-2 text-center text-xs">
+                            {[
+                              ['Packets/s', `${Math.round(data.rates.rx_packets)} / ${Math.round(data.rates.tx_packets)}`, false],
+                              ['Errors', `${data.counters.rx_errors} / ${data.counters.tx_errors}`, data.rates.rx_errors + data.rates.tx_errors > 0],
+                              ['Drops', `${data.counters.rx_dropped} / ${data.counters.tx_dropped}`, data.rates.rx_dropped + data.rates.tx_dropped > 0],
+                              ['Coll. / mcast', `${data.counters.collisions} / ${data.counters.multicast}`, false],
+                            ].map(([label, value, rising]) => (
+                              <div key={label} className="p-1.5 rounded bg-sci-bg-light dark:bg-sci-bg-dark" title="Receive / transmit">
+                                <div className="text-sci-text-light-secondary dark:text-sci-text-dark-secondary">{label}</div>
+                                <div className={clsx("font-mono", rising ? "text-red-600 dark:text-red-400" : "text-sci-text-light dark:text-sci-text-dark")}>
+                                  {value}
+                                </div>
+                              </div>
+                            ))}
+                          </div>
+                        )}
+                        {(data.error_history?.some((v) => v > 0) || data.drop_history?.some((v) => v > 0)) && (
+                          <div className="h-20">
+                            <HistoricalChart
+                              data={data.error_history.map((errors, i) => errors + (data.drop_history[i] ?? 0))}
+                              timestamps={timestampsFor(data, data.error_history.length)}
+                              label="Errors + drops"
+                              yAxisLabel="/s"
+                              color={uploadColor}
+                              fillColor={uploadFillColor}
+                              valueFormatter={(val) => `${val.toFixed(1)}/s`}
+                              minY={0}
+                            />
+                          </div>
+                        )}
+                      </div>
+                    )}
+
                     {/* Interface Charts */}
                     {data.rx_history && data.rx_history.length > 0 && (
                       <div className="p-4">
@@ -353,7 +413,7 @@ function NetworkTab() {
                             <div className="h-24">
                               <HistoricalChart 
                                 data={data.rx_history}
-                                timestamps={timestamps.slice(-data.rx_history.length)}
+                                timestamps={timestampsFor(data, data.rx_history.length)}
                                 label="Download"
                                 yAxisLabel="KB/s"
                                 color={downloadColor}
@@ -390,7 +450,7 @@ function NetworkTab() {
                               <div className="h-24">
                                 <HistoricalChart 
                                   data={data.tx_history}
-                                  timestamps={timestamps.slice(-data.tx_history.length)}
+                                  timestamps={timestampsFor(data, data.tx_history.length)}
                                   label="Upload"
                                   yAxisLabel="KB/s"
                                   color={uploadColor}
@@ -423,6 +483,9 @@ function NetworkTab() {
               })}
             </div>
           </div>
+
+          {/* Sockets with their owning processes */}
+          <ConnectionsTable />
         </>
       )}
     </div>
@@ -2,11 +2,14 @@ import React, { useState } from 'react';
 import { useSelector } from 'react-redux';
 import { motion } from 'framer-motion';
 import clsx from 'clsx';
-import { LuCpu, LuMemoryStick, LuNetwork, LuDisc3, LuActivity, LuThermometer, LuDatabase, LuPower, LuList, LuX } from "react-icons/lu";
+import { LuCpu, LuMemoryStick, LuNetwork, LuDisc3, LuActivity, LuThermometer, LuDatabase, LuPower, LuList, LuX, LuZap } from "react-icons/lu";
 import { BsGpuCard } from "react-icons/bs";
 
-import { formatSpeed, formatBytes, getMemoryColorClass, getCpuColorClass } from '../utils/formatting';
+import { formatSpeed, formatBytes, getMemoryColorClass, getCpuColorClass, buildHistoryTimestamps } from '../utils/formatting';
 import HistoricalChart from './HistoricalChart';
+import PressurePanel from './PressurePanel';
+import PowerPanel from './PowerPanel';
+import { terminateProcess } from '../utils/processControl';
 
 // Animation variants for panels - standardized with other tabs
 const panelVariants = {
@@ -118,8 +121,10 @@ function OverviewTab() {
     network_data = {},
     disk_data = {}, // Use disk_data map
     gpu_data = [],
+    cpu_power = null,
     top_processes = [],
     system_disk_read_history = [], system_disk_write_history = [],
+    cpu_history_timestamps = [], memory_history_timestamps = [], update_interval_ms = 1000,
     timestamp
   } = systemData;
 
@@ -139,32 +144,28 @@ function OverviewTab() {
   let totalSimulatedRead = 0;
   let totalSimulatedWrite = 0;
   Object.values(disk_data || {}).forEach(disk => {
-      totalSimulatedRead += disk.read_bytes_per_sec || 0;
-      totalSimulatedWrite += disk.write_bytes_per_sec || 0;
+      totalSimulatedRead += disk.read_kb_per_sec || 0;
+      totalSimulatedWrite += disk.write_kb_per_sec || 0;
   });
 
   // GPU & Process Prep
   const gpus = gpu_data || [];
   const topProcesses = top_processes?.slice(0, 6) || [];
+  // Measured draw: RAPL package power plus what the GPUs report (not the whole system's wall power)
+  const gpuPower = gpus.reduce((sum, gpu) => sum + (gpu.power_usage || 0), 0);
+  const measuredPower = cpu_power ? cpu_power.package_power_w + gpuPower : null;
 
   // History Prep
   const cpuHistoryAvg = filterHistory(cpu_history);
   const memHistoryPercent = filterHistory(memory_history);
-  const networkRxHistory = filterHistory(Object.values(network_data || {})?.[0]?.rx_history);
+  const firstNetwork = Object.values(network_data || {})?.[0];
+  const networkRxHistory = filterHistory(firstNetwork?.rx_history);
   const diskReadHistForChart = filterHistory(system_disk_read_history);
   const diskWriteHistForChart = filterHistory(system_disk_write_history);
   const diskTotalHistForChart = diskReadHistForChart.map((r, i) => (r || 0) + (diskWriteHistForChart[i] || 0));
 
-  // Estimate timestamps
-   const historyLength = Math.max(
-       cpuHistoryAvg.length, memHistoryPercent.length, diskReadHistForChart.length, networkRxHistory.length, 1
-   );
-   const chartTimestamps = React.useMemo(() => Array.from({ length: historyLength }, (_, i) =>
-       new Date(Date.now() - (historyLength - i - 1) * 1000).toISOString()
-   ), [historyLength]);
-
   // Helper to render a main metric panel
-  const renderMainPanel = (id, title, icon, value, unit, historyData, historyLabel, historyColor, historyFill, index, details = null, showChart = true) => (
+  const renderMainPanel = (id, title, icon, value, unit, historyData, historyTimestamps, historyLabel, historyColor, historyFill, index, details = null, showChart = true) => (
      <motion.div 
        className={clsx(
          "flex flex-col min-h-[180px] relative overflow-hidden rounded-md",
@@ -210,7 +211,7 @@ function OverviewTab() {
                 <div className="mt-5 h-36">
                   <HistoricalChart 
                     data={historyData}
-                    timestamps={chartTimestamps}
+                    timestamps={buildHistoryTimestamps(historyTimestamps, historyData.length, update_interval_ms)}
                     label={historyLabel}
                     yAxisLabel={unit === '%' ? 'Usage (%)' : ''}
                     color={historyColor}
@@ -291,10 +292,10 @@ function OverviewTab() {
                     <div className="font-medium text-sci-text-light dark:text-sci-text-dark truncate" title={name}>{name}</div>
                     <div className="flex justify-between text-sci-text-light-secondary dark:text-sci-text-dark-secondary font-mono">
                       <span className="inline-flex items-center" title="Read speed">
-                        R: {formatSpeed(disk.read_bytes_per_sec || 0)}/s
+                        R: {formatSpeed(disk.read_kb_per_sec || 0)}/s
                       </span>
                       <span className="inline-flex items-center" title="Write speed">
-                        W: {formatSpeed(disk.write_bytes_per_sec || 0)}/s
+                        W: {formatSpeed(disk.write_kb_per_sec || 0)}/s
                       </span>
                     </div>
                   </div>
@@ -318,6 +319,15 @@ function OverviewTab() {
           <LuActivity className="text-blue-500 dark:text-blue-400" />
           System Overview
         </h2>
+        {measuredPower != null && (
+          <span
+            className="flex items-center gap-1.5 text-sm text-sci-text-light-secondary dark:text-sci-text-dark-secondary"
+            title={`CPU package ${cpu_power.package_power_w.toFixed(1)} W + GPU ${gpuPower.toFixed(1)} W`}
+          >
+            <LuZap className="h-4 w-4 text-yellow-500" />
+            <span className="font-mono">{measuredPower.toFixed(1)} W</span>
+          </span>
+        )}
       </div>
       
       {/* Main Panels Row */}
@@ -329,6 +339,7 @@ function OverviewTab() {
           avgCpu.toFixed(1), 
           "%", 
           cpuHistoryAvg, 
+          cpu_history_timestamps,
           "CPU Usage", 
           "rgba(59, 130, 246, 1)", // blue-500
           "rgba(59, 130, 246, 0.1)", 
@@ -342,6 +353,7 @@ function OverviewTab() {
           memPercent.toFixed(1), 
           "%", 
           memHistoryPercent, 
+          memory_history_timestamps,
           "Memory Usage", 
           "rgba(236, 72, 153, 1)", // pink-500
           "rgba(236, 72, 153, 0.15)", 
@@ -356,6 +368,7 @@ function OverviewTab() {
           formatSpeed(totalNetworkRx + totalNetworkTx), 
           "", 
           networkRxHistory, 
+          firstNetwork?.history_timestamps,
           "Network Traffic", 
           "rgba(34, 197, 94, 1)", // green-500
           "rgba(34, 197, 94, 0.15)", 
@@ -470,6 +483,12 @@ function OverviewTab() {
           </div>
         </motion.div>
       </div>
+
+      {/* Batteries and AC state (laptops) */}
+      <PowerPanel />
+
+      {/* Contention, which utilization alone doesn't show */}
+      <PressurePanel />
     
       {/* Context Menu */}
       {contextMenu.visible && (
@@ -501,12 +520,13 @@ function OverviewTab() {
               "flex items-center gap-2",
               "hover:bg-sci-bg-light hover:dark:bg-sci-bg-dark"
             )}
-            onClick={() => {
+            onClick={async () => {
+              const process = contextMenu.process;
               // Close the context menu
               setContextMenu({ visible: false, x: 0, y: 0, process: null });
-              
-              // Display a simple alert message
-              alert("Process termination is yet to be implemented");
+              if (!process) return;
+
+              alert(await terminateProcess(process[0], process[1]));
             }}
           >
             <LuX className="h-4 w-4 text-red-500" />
@@ -0,0 +1,102 @@
+import React from 'react';
+import { useSelector } from 'react-redux';
+import clsx from 'clsx';
+import { LuPower, LuZap } from "react-icons/lu";
+import { buildHistoryTimestamps, formatDuration } from '../utils/formatting';
+import HistoricalChart from './HistoricalChart';
+
+const capacityColorClass = (percent) => {
+  if (percent <= 10) return 'bg-red-500';
+  if (percent <= 25) return 'bg-yellow-500';
+  return 'bg-green-500';
+};
+
+const formatWh = (value) => (value != null ? `${value.toFixed(1)} Wh` : '—');
+
+function BatteryCard({ battery, intervalMs }) {
+  const capacity = battery.capacity_percent ?? 0;
+  const estimate = battery.time_to_empty_secs != null
+    ? `${formatDuration(battery.time_to_empty_secs)} remaining`
+    : battery.time_to_full_secs != null
+      ? `${formatDuration(battery.time_to_full_secs)} until full`
+      : null;
+  const rows = [
+    ['Power', battery.power_w != null ? `${battery.power_w > 0 ? '+' : ''}${battery.power_w.toFixed(1)} W` : '—'],
+    ['Energy', `${formatWh(battery.energy_now_wh)} / ${formatWh(battery.energy_full_wh)}`],
+    ['Design capacity', formatWh(battery.energy_full_design_wh)],
+    ['Health', battery.health_percent != null ? `${battery.health_percent.toFixed(0)}%` : '—'],
+    ['Cycles', battery.cycle_count || '—'], // 0 usually means "not tracked"
+    ['Voltage', battery.voltage_v != null ? `${battery.voltage_v.toFixed(2)} V` : '—'],
+  ];
+
+  return (
+    <div className="space-y-3">
+      <div className="flex items-baseline justify-between gap-2">
+        <span className="text-sm font-semibold text-sci-text-light dark:text-sci-text-dark" title={[battery.manufacturer, battery.model_name, battery.technology].filter(Boolean).join(' ')}>
+          {battery.name}
+        </span>
+        <span className="text-xs text-sci-text-light-secondary dark:text-sci-text-dark-secondary">
+          {battery.status}{estimate && ` · ${estimate}`}
+        </span>
+      </div>
+      <div className="flex items-center gap-2">
+        <div className="flex-1 h-3 rounded bg-gray-200 dark:bg-gray-700 overflow-hidden">
+          <div className={clsx('h-full', capacityColorClass(capacity))} style={{ width: `${Math.min(capacity, 100)}%` }} />
+        </div>
+        <span className="font-mono text-sm text-sci-text-light dark:text-sci-text-dark">{capacity.toFixed(0)}%</span>
+      </div>
+      <dl className="grid grid-cols-2 gap-x-6 gap-y-1 text-xs">
+        {rows.map(([label, value]) => (
+          <div key={label} className="flex justify-between gap-4">
+            <dt className="text-sci-text-light-secondary dark:text-sci-text-dark-secondary">{label}</dt>
+            <dd className="font-mono text-sci-text-light dark:text-sci-text-dark">{value}</dd>
+          </div>
+        ))}
+      </dl>
+      <div className="h-40">
+        {battery.capacity_history.length > 0 && (
+          <HistoricalChart
+            data={battery.capacity_history}
+            timestamps={buildHistoryTimestamps(battery.history_timestamps, battery.capacity_history.length, intervalMs)}
+            label="Charge"
+            yAxisLabel="%"
+            color="rgba(34, 197, 94, 1)" // green-500
+            fillColor="rgba(34, 197, 94, 0.15)"
+            valueFormatter={(v) => `${v.toFixed(0)}%`}
+            minY={0}
+            maxY={100}
+          />
+        )}
+      </div>
+    </div>
+  );
+}
+
+function PowerPanel() {
+  const { power, update_interval_ms } = useSelector((state) => state.systemData);
+
+  // Desktops without a battery have nothing worth a panel
+  if (!power || power.batteries.length === 0) return null;
+
+  return (
+    <div className="rounded-md bg-sci-bg-light-alt dark:bg-sci-bg-dark-alt border border-sci-border-light dark:border-sci-border-dark shadow-sci-light dark:shadow-sci-dark">
+      <div className="p-4 border-b border-sci-border-light dark:border-sci-border-dark flex items-center gap-2">
+        <LuPower className="text-green-500 dark:text-green-400" />
+        <h3 className="text-base font-semibold text-sci-text-light dark:text-sci-text-dark">Power</h3>
+        {power.ac_online != null && (
+          <span className={clsx('ml-auto flex items-center gap-1 text-xs', power.ac_online ? 'text-green-600 dark:text-green-400' : 'text-yellow-600 dark:text-yellow-400')}>
+            <LuZap className="w-3.5 h-3.5" />
+            {power.ac_online ? 'On AC power' : 'On battery'}
+          </span>
+        )}
+      </div>
+      <div className={clsx('p-4 grid gap-6', power.batteries.length > 1 ? 'grid-cols-1 lg:grid-cols-2' : 'grid-cols-1')}>
+        {power.batteries.map((battery) => (
+          <BatteryCard key={battery.name} battery={battery} intervalMs={update_interval_ms} />
+        ))}
+      </div>
+    </div>
+  );
+}
+
+export default PowerPanel;
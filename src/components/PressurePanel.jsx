@@ -0,0 +1,131 @@
+import React from 'react';
+import { useSelector } from 'react-redux';
+import clsx from 'clsx';
+import { LuTriangleAlert } from "react-icons/lu";
+import { buildHistoryTimestamps } from '../utils/formatting';
+import HistoricalChart from './HistoricalChart';
+
+const RESOURCES = [
+  { key: 'cpu', label: 'CPU', color: 'rgba(59, 130, 246, 1)' }, // blue-500
+  { key: 'memory', label: 'Memory', color: 'rgba(236, 72, 153, 1)' }, // pink-500
+  { key: 'io', label: 'I/O', color: 'rgba(234, 179, 8, 1)' }, // yellow-500
+];
+
+// PSI percentages: a few percent is already noticeable, double digits feel slow
+const pressureColorClass = (value) => {
+  if (value >= 20) return 'text-red-500 dark:text-red-400';
+  if (value >= 5) return 'text-yellow-600 dark:text-yellow-400';
+  return 'text-sci-text-light dark:text-sci-text-dark';
+};
+
+const formatPressure = (value) => `${(value ?? 0).toFixed(2)}%`;
+
+function StatRow({ label, stat, hint }) {
+  return (
+    <div className="flex justify-between gap-2" title={hint}>
+      <span className="text-sci-text-light-secondary dark:text-sci-text-dark-secondary">{label}</span>
+      {stat ? (
+        <span className="font-mono">
+          {[stat.avg10, stat.avg60, stat.avg300].map((value, index) => (
+            <span key={index} className={clsx('inline-block w-14 text-right', pressureColorClass(value))}>{formatPressure(value)}</span>
+          ))}
+        </span>
+      ) : (
+        <span className="font-mono text-sci-text-light-secondary dark:text-sci-text-dark-secondary">—</span>
+      )}
+    </div>
+  );
+}
+
+function PressurePanel() {
+  const { pressure, update_interval_ms } = useSelector((state) => state.systemData);
+
+  if (!pressure) return null;
+
+  const resources = RESOURCES.filter(({ key }) => pressure[key]);
+  const series = resources.map(({ key, label, color }) => ({
+    label: `${label} some`,
+    data: pressure[key].some.history,
+    color,
+    fillColor: color,
+  }));
+  const historyLength = series[0]?.data.length ?? 0;
+  // All resources are sampled in the same cycle, so the first one's times label the chart
+  const historyTimestamps = resources.length > 0 ? pressure[resources[0].key].some.history_timestamps : [];
+  // Worst first, by the highest 10s average of any resource
+  const worst = (cgroup) => Math.max(...RESOURCES.map(({ key }) => cgroup[key]?.some.avg10 ?? 0));
+  const cgroups = [...pressure.cgroups].sort((a, b) => worst(b) - worst(a));
+
+  return (
+    <div className="rounded-md bg-sci-bg-light-alt dark:bg-sci-bg-dark-alt border border-sci-border-light dark:border-sci-border-dark shadow-sci-light dark:shadow-sci-dark">
+      <div className="p-4 border-b border-sci-border-light dark:border-sci-border-dark flex items-center gap-2">
+        <LuTriangleAlert className="text-yellow-500 dark:text-yellow-400" />
+        <h3 className="text-base font-semibold text-sci-text-light dark:text-sci-text-dark">Pressure Stall Information</h3>
+        <span className="text-xs text-sci-text-light-secondary dark:text-sci-text-dark-secondary">
+          share of time tasks waited · avg 10s / 60s / 300s
+        </span>
+      </div>
+
+      <div className="p-4 grid grid-cols-1 lg:grid-cols-2 gap-4">
+        <div className="space-y-3 text-xs text-sci-text-light dark:text-sci-text-dark">
+          {RESOURCES.map(({ key, label }) => (
+            <div key={key}>
+              <div className="font-semibold mb-1">{label}</div>
+              <StatRow label="some" stat={pressure[key]?.some} hint="At least one task stalled" />
+              <StatRow label="full" stat={pressure[key]?.full} hint="All non-idle tasks stalled at once" />
+            </div>
+          ))}
+        </div>
+
+        <div className="h-56">
+          {historyLength > 0 && (
+            <HistoricalChart
+              series={series}
+              stacked={false}
+              timestamps={buildHistoryTimestamps(historyTimestamps, historyLength, update_interval_ms)}
+              yAxisLabel="% stalled"
+              valueFormatter={formatPressure}
+              minY={0}
+            />
+          )}
+        </div>
+      </div>
+
+      {cgroups.length > 0 && (
+        <div className="overflow-auto max-h-64 border-t border-sci-border-light dark:border-sci-border-dark">
+          <table className="w-full text-xs">
+            <thead>
+              <tr className="border-b border-sci-border-light dark:border-sci-border-dark text-sci-text-light-secondary dark:text-sci-text-dark-secondary">
+                <th className="p-2 text-left font-medium">cgroup</th>
+                {RESOURCES.map(({ key, label }) => (
+                  <th key={key} className="p-2 text-left font-medium" title="some / full, 10s average">{label}</th>
+                ))}
+              </tr>
+            </thead>
+            <tbody>
+              {cgroups.map((cgroup) => (
+                <tr key={cgroup.path} className="border-b border-sci-border-light dark:border-sci-border-dark font-mono text-sci-text-light dark:text-sci-text-dark">
+                  <td className="p-2 truncate max-w-xs" title={cgroup.path}>{cgroup.path}</td>
+                  {RESOURCES.map(({ key }) => (
+                    <td key={key} className="p-2">
+                      {cgroup[key] ? (
+                        <>
+                          <span className={pressureColorClass(cgroup[key].some.avg10)}>{formatPressure(cgroup[key].some.avg10)}</span>
+                          {cgroup[key].full && (
+                            <span className={pressureColorClass(cgroup[key].full.avg10)}> / {formatPressure(cgroup[key].full.avg10)}</span>
+                          )}
+                        </>
+                      ) : '—'}
+                    </td>
+                  ))}
+                </tr>
+              ))}
+            </tbody>
+          </table>
+        </div>
+      )}
+    </div>
+  );
+}
+
+export default PressurePanel;
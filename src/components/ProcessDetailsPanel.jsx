@@ -0,0 +1,88 @@
+import React, { useState, useEffect } from 'react';
+import { useSelector } from 'react-redux';
+import { invoke } from '@tauri-apps/api/core';
+import { LuInfo } from "react-icons/lu";
+import { formatPercent, formatDuration } from '../utils/formatting';
+import { shortContainerId } from './CgroupsPanel';
+
+const valueOrDash = (value) => (value === null || value === undefined || value === '' ? '—' : value);
+
+function ProcessDetailsPanel({ pid }) {
+  const { timestamp } = useSelector((state) => state.systemData);
+  const [details, setDetails] = useState(null);
+  const [error, setError] = useState(null);
+  const [showEnvironment, setShowEnvironment] = useState(false);
+
+  // Refetch on every update so CPU/memory stay live
+  useEffect(() => {
+    let cancelled = false;
+    invoke('get_process_details', { pid })
+      .then((result) => { if (!cancelled) { setDetails(result); setError(null); } })
+      .catch((e) => { if (!cancelled) { setDetails(null); setError(`${e}`); } });
+    return () => { cancelled = true; };
+  }, [pid, timestamp]);
+
+  if (error) {
+    return <div className="p-4 text-sm text-sci-text-light-secondary dark:text-sci-text-dark-secondary">{error}</div>;
+  }
+  if (!details) return null;
+
+  const rows = [
+    ['Executable', details.exe],
+    ['Working directory', details.cwd],
+    ['User', details.user ? `${details.user} (${valueOrDash(details.uid)})` : details.uid],
+    ['State', details.status],
+    ['Started', new Date(details.start_time * 1000).toLocaleString()],
+    ['Running for', formatDuration(details.run_time)],
+    ['Parent PID', details.parent_pid],
+    ['Session / group', `${valueOrDash(details.session_id)} / ${valueOrDash(details.process_group)}`],
+    ['Threads', details.thread_count],
+    ['Nice / priority', `${valueOrDash(details.nice)} / ${valueOrDash(details.priority)}`],
+    ['Open files', details.open_files],
+    ['cgroup', details.cgroup],
+    ['Container', details.container ? `${details.container.runtime} ${shortContainerId(details.container.id)}` : null],
+    ['CPU', formatPercent(details.cpu_usage)],
+    ['Memory (RSS / virtual)', `${details.memory_mb.toFixed(1)} MB / ${details.virtual_memory_mb.toFixed(1)} MB`],
+  ];
+
+  return (
+    <div className="p-4 space-y-3">
+      <div className="flex items-center gap-2">
+        <LuInfo className="text-purple-500 dark:text-purple-400" />
+        <span className="text-sm font-semibold text-sci-text-light dark:text-sci-text-dark">
+          {details.name} ({details.pid})
+        </span>
+      </div>
+      <div className="font-mono text-xs break-all p-2 rounded bg-sci-bg-light dark:bg-sci-bg-dark text-sci-text-light dark:text-sci-text-dark">
+        {details.cmd.length > 0 ? details.cmd.join(' ') : `[${details.name}]`}
+      </div>
+      <dl className="grid grid-cols-1 md:grid-cols-2 gap-x-6 gap-y-1 text-xs">
+        {rows.map(([label, value]) => (
+          <div key={label} className="flex justify-between gap-4">
+            <dt className="text-sci-text-light-secondary dark:text-sci-text-dark-secondary">{label}</dt>
+            <dd className="font-mono text-right truncate text-sci-text-light dark:text-sci-text-dark" title={`${valueOrDash(value)}`}>
+              {valueOrDash(value)}
+            </dd>
+          </div>
+        ))}
+      </dl>
+      {details.environ.length > 0 && (
+        <div>
+          <button
+            className="text-xs text-purple-600 dark:text-purple-400 hover:underline"
+            onClick={() => setShowEnvironment(!showEnvironment)}
+          >
+            {showEnvironment ? 'Hide' : 'Show'} environment ({details.environ.length})
+          </button>
+          {showEnvironment && (
+            <pre className="mt-2 max-h-48 overflow-auto text-xs p-2 rounded bg-sci-bg-light dark:bg-sci-bg-dark text-sci-text-light dark:text-sci-text-dark">
+              {details.environ.join('\n')}
+            </pre>
+          )}
+        </div>
+      )}
+    </div>
+  );
+}
+
+export default ProcessDetailsPanel;
@@ -0,0 +1,101 @@
+import React, { useState, useEffect } from 'react';
+import { useSelector } from 'react-redux';
+import { invoke } from '@tauri-apps/api/core';
+import { LuChevronRight, LuChevronDown } from "react-icons/lu";
+import { getCpuColorClass, formatPercent, truncateText } from '../utils/formatting';
+
+// kthreadd's children are hundreds of kernel workers; start with them folded away
+const INITIALLY_COLLAPSED = [2];
+
+// Flattens the nested tree into rows with their depth, in display order
+const flattenTree = (nodes, depth = 0, rows = []) => {
+  nodes.forEach((node) => {
+    rows.push({ node, depth });
+    flattenTree(node.children, depth + 1, rows);
+  });
+  return rows;
+};
+
+function ProcessTree({ onContextMenu }) {
+  const { timestamp } = useSelector((state) => state.systemData);
+  const [tree, setTree] = useState([]);
+  const [collapsed, setCollapsed] = useState(INITIALLY_COLLAPSED);
+  const [error, setError] = useState(null);
+
+  // Refetch on every update; collapsed nodes are omitted by the backend
+  useEffect(() => {
+    let cancelled = false;
+    invoke('get_process_tree', { collapsed })
+      .then((result) => { if (!cancelled) { setTree(result); setError(null); } })
+      .catch((e) => { if (!cancelled) setError(`${e}`); });
+    return () => { cancelled = true; };
+  }, [timestamp, collapsed]);
+
+  const toggle = (pid) => {
+    setCollapsed((current) => current.includes(pid) ? current.filter((p) => p !== pid) : [...current, pid]);
+  };
+
+  if (error) {
+    return <div className="p-4 text-sm text-red-500">Failed to load process tree: {error}</div>;
+  }
+
+  return (
+    <div className="overflow-auto">
+      <table className="w-full">
+        <thead>
+          <tr className="border-b border-sci-border-light dark:border-sci-border-dark text-sm text-sci-text-light-secondary dark:text-sci-text-dark-secondary">
+            <th className="p-3 text-left font-medium">Process</th>
+            <th className="p-3 text-left font-medium">PID</th>
+            <th className="p-3 text-left font-medium" title="Own CPU / including children">CPU (self / tree)</th>
+            <th className="p-3 text-left font-medium" title="Own RSS / including children">Memory (self / tree)</th>
+          </tr>
+        </thead>
+        <tbody>
+          {flattenTree(tree).map(({ node, depth }) => {
+            const hasChildren = node.child_count > 0;
+            return (
+              <tr
+                key={node.pid}
+                className="border-b border-sci-border-light dark:border-sci-border-dark hover:bg-sci-bg-light hover:dark:bg-sci-bg-dark"
+                onContextMenu={(e) => onContextMenu?.(e, [node.pid, node.name, node.cpu_usage, Math.round(node.memory_mb)])}
+              >
+                <td className="p-2 text-sm text-sci-text-light dark:text-sci-text-dark">
+                  <div className="flex items-center gap-1" style={{ paddingLeft: `${depth * 16}px` }}>
+                    {hasChildren ? (
+                      <button onClick={() => toggle(node.pid)} className="p-0.5 opacity-70 hover:opacity-100" title={node.collapsed ? 'Expand' : 'Collapse'}>
+                        {node.collapsed ? <LuChevronRight className="w-3.5 h-3.5" /> : <LuChevronDown className="w-3.5 h-3.5" />}
+                      </button>
+                    ) : (
+                      <span className="inline-block" style={{ width: '18px' }} />
+                    )}
+                    <span title={node.name}>{truncateText(node.name, 40)}</span>
+                    {hasChildren && (
+                      <span className="text-xs text-sci-text-light-secondary dark:text-sci-text-dark-secondary">
+                        ({node.descendant_count})
+                      </span>
+                    )}
+                  </div>
+                </td>
+                <td className="p-2 text-sm font-mono text-sci-text-light dark:text-sci-text-dark">{node.pid}</td>
+                <td className="p-2 text-sm font-mono">
+                  <span className={getCpuColorClass(node.cpu_usage)}>{formatPercent(node.cpu_usage)}</span>
+                  {hasChildren && (
+                    <span className="text-sci-text-light-secondary dark:text-sci-text-dark-secondary"> / {formatPercent(node.subtree_cpu_usage)}</span>
+                  )}
+                </td>
+                <td className="p-2 text-sm font-mono text-sci-text-light dark:text-sci-text-dark">
+                  {Math.round(node.memory_mb)} MB
+                  {hasChildren && (
+                    <span className="text-sci-text-light-secondary dark:text-sci-text-dark-secondary"> / {Math.round(node.subtree_memory_mb)} MB</span>
+                  )}
+                </td>
+              </tr>
+            );
+          })}
+        </tbody>
+      </table>
+    </div>
+  );
+}
+
+export default ProcessTree;
@@ -1,10 +1,16 @@
 import React, { useState, useEffect, useRef } from 'react';
+import { invoke } from '@tauri-apps/api/core';
 import { useSelector } from 'react-redux';
 import { motion } from 'framer-motion';
 import clsx from 'clsx';
-import { LuList, LuCpu, LuActivity, LuSearch, LuRefreshCw, LuX, LuTriangleAlert, LuFilter, LuArrowUp, LuArrowDown, LuMemoryStick, LuCommand, LuInfo } from "react-icons/lu";
+import { LuList, LuCpu, LuActivity, LuSearch, LuRefreshCw, LuX, LuTriangleAlert, LuFilter, LuArrowUp, LuArrowDown, LuMemoryStick, LuCommand, LuInfo, LuListTree, LuServer, LuPower } from "react-icons/lu";
 import { getCpuColorClass, truncateText, formatPercent } from '../utils/formatting';
 import HistoricalChart from './HistoricalChart';
+import ProcessTree from './ProcessTree';
+import CgroupsPanel, { shortContainerId } from './CgroupsPanel';
+import SystemdUnitsPanel from './SystemdUnitsPanel';
+import ProcessDetailsPanel from './ProcessDetailsPanel';
+import { terminateProcess } from '../utils/processControl';
 
 // Animation variants for panels
 const panelVariants = {
@@ -34,6 +40,9 @@ const rowVariants = {
   })
 };
 
+// Rows per page of the backend process table
+const PAGE_SIZE = 50;
+
 function ProcessesTab() {
   const { top_processes = [], timestamp } = useSelector((state) => state.systemData);
   const [sortBy, setSortBy] = useState('cpu'); // Default sort by CPU usage
@@ -44,8 +53,31 @@ function ProcessesTab() {
   const [selectedProcess, setSelectedProcess] = useState(null);
   const [confirmKillOpen, setConfirmKillOpen] = useState(false);
   const [contextMenu, setContextMenu] = useState({ visible: false, x: 0, y: 0, process: null });
+  const [processPage, setProcessPage] = useState(null); // Full table page from get_process_table
+  const [page, setPage] = useState(0);
+  const [refreshCounter, setRefreshCounter] = useState(0);
+  const [viewMode, setViewMode] = useState('list'); // 'list' | 'tree' | 'cgroups' | 'units'
   const searchInputRef = useRef(null);
 
+  // Fetch the sorted/filtered page from the backend on every update and whenever the view changes
+  useEffect(() => {
+    let cancelled = false;
+    const query = {
+      sort_by: sortBy,
+      descending: sortDirection === 'desc',
+      filter: searchTerm || null,
+      offset: page * PAGE_SIZE,
+      limit: PAGE_SIZE,
+    };
+    invoke('get_process_table', { query })
+      .then((result) => { if (!cancelled) setProcessPage(result); })
+      .catch((error) => {
+        console.error('Failed to fetch process table:', error);
+        if (!cancelled) setProcessPage(null); // Fall back to the top processes
+      });
+    return () => { cancelled = true; };
+  }, [timestamp, sortBy, sortDirection, searchTerm, page, refreshCounter]);
+
   // Store process history for top processes
   useEffect(() => {
     if (top_processes.length > 0 && timestamp) {
@@ -176,16 +208,19 @@ function ProcessesTab() {
       setSortBy(field);
       setSortDirection(field === 'name' ? 'asc' : 'desc');
     }
+    setPage(0);
   };
 
   // Handle search input
   const handleSearch = (e) => {
     setSearchTerm(e.target.value);
+    setPage(0);
   };
   
   // Handle search clear
   const clearSearch = () => {
     setSearchTerm('');
+    setPage(0);
     if (searchInputRef.current) {
       searchInputRef.current.focus();
     }
@@ -212,16 +247,26 @@ function ProcessesTab() {
   };
 
   // Handle kill process
-  const handleKillProcess = () => {
+  const handleKillProcess = async () => {
+    const process = contextMenu.process;
     // Close the context menu
     setContextMenu({ visible: false, x: 0, y: 0, process: null });
-    
-    // Display a simple alert message instead of using the modal
-    alert("Process termination is yet to be implemented");
+    if (!process) return;
+
+    // Name is passed along so a recycled PID is never signalled
+    alert(await terminateProcess(process[0], process[1]));
   };
 
   // Process and sort data for display
-  const processesToDisplay = filterProcesses(sortProcesses(top_processes));
+  // Rows keep the [pid, name, cpu, memMB] tuple shape used by the history and context menu
+  const processesToDisplay = processPage
+    ? processPage.processes.map((p) => [p.pid, p.name, p.cpu_usage, Math.round(p.memory_mb)])
+    : filterProcesses(sortProcesses(top_processes));
+  // Container of each row on the current page (tagged by the backend from /proc/<pid>/cgroup)
+  const containerByPid = new Map((processPage?.processes ?? []).filter((p) => p.container).map((p) => [p.pid, p.container]));
+  const totalProcessCount = processPage ? processPage.total : top_processes.length;
+  const matchedProcessCount = processPage ? processPage.matched : processesToDisplay.length;
+  const pageCount = Math.max(1, Math.ceil(matchedProcessCount / PAGE_SIZE));
   
   // Get the top 5 processes for historical display based on current sort
   const topProcessesForHistory = processesToDisplay.slice(0, 5);
@@ -338,6 +383,7 @@ function ProcessesTab() {
                   "focus:outline-none focus:ring-2 focus:ring-purple-500 dark:focus:ring-purple-400"
                 )}
                 title="Refresh process list"
+                onClick={() => setRefreshCounter((count) => count + 1)}
               >
                 <LuRefreshCw className="h-5 w-5" />
               </button>
@@ -368,7 +414,7 @@ function ProcessesTab() {
                 Total Processes
               </div>
               <div className="text-xl font-mono font-medium text-sci-text-light dark:text-sci-text-dark">
-                {top_processes.length}
+                {totalProcessCount}
               </div>
             </div>
             
@@ -378,10 +424,10 @@ function ProcessesTab() {
               "border border-sci-border-light dark:border-sci-border-dark"
             )}>
               <div className="text-xs uppercase tracking-wider text-sci-text-light-secondary dark:text-sci-text-dark-secondary mb-1">
-                Displayed
+                Matching
               </div>
               <div className="text-xl font-mono font-medium text-sci-text-light dark:text-sci-text-dark">
-                {processesToDisplay.length}
+                {matchedProcessCount}
               </div>
             </div>
             
@@ -410,12 +456,18 @@ function ProcessesTab() {
           </div>
           
           <div className="mt-4 text-xs text-sci-text-light-secondary dark:text-sci-text-dark-secondary italic">
-            Tip: Right-click on a process to open the context menu for more options.
+            Tip: Click a process for details, right-click for more options.
           </div>
+
+          {selectedProcess && (
+            <div className="mt-4 rounded-md border border-sci-border-light dark:border-sci-border-dark">
+              <ProcessDetailsPanel pid={selectedProcess[0]} />
+            </div>
+          )}
         </div>
       </motion.div>
       
-      {top_processes.length === 0 ? (
+      {totalProcessCount === 0 ? (
         <motion.div 
           className={clsx(
             "p-8 text-center rounded-md",
@@ -452,11 +504,36 @@ function ProcessesTab() {
               <div className="flex items-center gap-2">
                 <LuCommand className="text-purple-500 dark:text-purple-400" />
                 <h3 className="text-base font-semibold text-sci-text-light dark:text-sci-text-dark">
-                  Process List
+                  {{ tree: 'Process Tree', cgroups: 'cgroups & Containers', units: 'systemd Units' }[viewMode] ?? 'Process List'}
                 </h3>
+                <div className="ml-auto flex gap-1">
+                  {[['list', LuList, 'Flat list'], ['tree', LuListTree, 'Tree by parent'], ['cgroups', LuServer, 'cgroups and containers'], ['units', LuPower, 'Grouped by systemd unit']].map(([mode, Icon, title]) => (
+                    <button
+                      key={mode}
+                      title={title}
+                      onClick={() => setViewMode(mode)}
+                      className={clsx(
+                        "p-1.5 rounded-md border border-sci-border-light dark:border-sci-border-dark",
+                        viewMode === mode
+                          ? "text-purple-600 dark:text-purple-400 bg-purple-50 dark:bg-purple-900/20"
+                          : "text-sci-text-light-secondary dark:text-sci-text-dark-secondary"
+                      )}
+                    >
+                      <Icon className="h-4 w-4" />
+                    </button>
+                  ))}
+                </div>
               </div>
             </div>
             
+            {viewMode === 'tree' ? (
+              <ProcessTree onContextMenu={handleContextMenu} />
+            ) : viewMode === 'cgroups' ? (
+              <CgroupsPanel />
+            ) : viewMode === 'units' ? (
+              <SystemdUnitsPanel onContextMenu={handleContextMenu} />
+            ) : (
+            <>
             <div className="overflow-auto">
               <table className="w-full">
                 <thead>
@@ -546,8 +623,18 @@ function ProcessesTab() {
                             {pid}
                           </td>
                           <td className="p-3 text-sm text-sci-text-light dark:text-sci-text-dark">
-                            <div className="truncate max-w-xs" title={name}>
-                              {name}
+                            <div className="flex items-center gap-2">
+                              <div className="truncate max-w-xs" title={name}>
+                                {name}
+                              </div>
+                              {containerByPid.has(pid) && (
+                                <span
+                                  className="text-xs px-1.5 rounded bg-blue-100 text-blue-700 dark:bg-blue-900/40 dark:text-blue-300 font-mono"
+                                  title={`${containerByPid.get(pid).runtime} container ${containerByPid.get(pid).id}`}
+                                >
+                                  {shortContainerId(containerByPid.get(pid).id)}
+                                </span>
+                              )}
                             </div>
                           </td>
                           <td className="p-3 text-sm">
@@ -576,6 +663,32 @@ function ProcessesTab() {
                 </tbody>
               </table>
             </div>
+            {processPage && pageCount > 1 && (
+              <div className="flex items-center justify-between p-3 text-xs text-sci-text-light-secondary dark:text-sci-text-dark-secondary">
+                <span>
+                  {processPage.offset + 1}–{processPage.offset + processesToDisplay.length} of {matchedProcessCount}
+                </span>
+                <div className="flex items-center gap-2">
+                  <button
+                    className="px-2 py-1 rounded border border-sci-border-light dark:border-sci-border-dark disabled:opacity-40"
+                    onClick={() => setPage((current) => Math.max(0, current - 1))}
+                    disabled={page === 0}
+                  >
+                    Previous
+                  </button>
+                  <span className="font-mono">{page + 1} / {pageCount}</span>
+                  <button
+                    className="px-2 py-1 rounded border border-sci-border-light dark:border-sci-border-dark disabled:opacity-40"
+                    onClick={() => setPage((current) => Math.min(pageCount - 1, current + 1))}
+                    disabled={page >= pageCount - 1}
+                  >
+                    Next
+                  </button>
+                </div>
+              </div>
+            )}
+            </>
+            )}
           </motion.div>
           
           {/* Top Processes History - Moved to the end as requested */}
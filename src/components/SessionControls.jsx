@@ -0,0 +1,126 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { useDispatch } from 'react-redux';
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import clsx from 'clsx';
+import { LuDisc, LuArchive, LuChevronRight, LuX } from "react-icons/lu";
+import { formatBytes } from '../utils/formatting';
+import { setBackendError } from '../features/error/errorSlice';
+
+const REPLAY_SPEEDS = [1, 2, 5, 10, 30];
+
+const buttonClass = "p-1.5 rounded text-xl text-sci-text-light-secondary hover:bg-sci-bg-light hover:text-sci-text-light dark:text-sci-text-dark-secondary dark:hover:bg-sci-bg-dark-alt dark:hover:text-sci-text-dark";
+
+// Record the live stream to a session file, or replay one through the regular system-update event
+function SessionControls() {
+  const dispatch = useDispatch();
+  const [status, setStatus] = useState({ recording: null, replay: null });
+  const [sessions, setSessions] = useState([]);
+  const [menuOpen, setMenuOpen] = useState(false);
+  const [speed, setSpeed] = useState(1);
+
+  const reportError = useCallback((e) => dispatch(setBackendError(`${e}`)), [dispatch]);
+
+  const run = useCallback((command, args) => {
+    invoke(command, args).then(setStatus).catch(reportError);
+  }, [reportError]);
+
+  useEffect(() => {
+    invoke('get_session_status').then(setStatus).catch(() => {});
+    let unlisten = () => {};
+    listen('session-status', (event) => setStatus(event.payload)).then((fn) => { unlisten = fn; });
+    return () => unlisten();
+  }, []);
+
+  // Frame counters only move in the backend, so poll while something is running
+  const active = !!(status.recording || status.replay);
+  useEffect(() => {
+    if (!active) return undefined;
+    const timer = setInterval(() => invoke('get_session_status').then(setStatus).catch(() => {}), 1000);
+    return () => clearInterval(timer);
+  }, [active]);
+
+  const toggleMenu = () => {
+    if (!menuOpen) {
+      invoke('list_sessions').then(setSessions).catch(reportError);
+    }
+    setMenuOpen(!menuOpen);
+  };
+
+  const startReplay = (path) => {
+    setMenuOpen(false);
+    run('start_replay', { path, speed });
+  };
+
+  const fileName = (path) => path.split(/[\\/]/).pop();
+
+  return (
+    <div className="relative flex items-center space-x-1">
+      {status.replay && (
+        <div className="flex items-center gap-1.5 px-2 py-0.5 rounded text-xs bg-purple-100 text-purple-700 dark:bg-purple-900/40 dark:text-purple-300" title={status.replay.path}>
+          <span className="font-semibold">REPLAY</span>
+          <span className="font-mono">{status.replay.position}/{status.replay.total} · {status.replay.speed}x</span>
+          <button onClick={() => run('stop_replay')} title="Stop replay and return to live data" className="opacity-70 hover:opacity-100">
+            <LuX className="w-3.5 h-3.5" />
+          </button>
+        </div>
+      )}
+      <button
+        onClick={() => run(status.recording ? 'stop_recording' : 'start_recording')}
+        disabled={!!status.replay}
+        title={status.recording ? `Recording ${status.recording.frames} frames to ${status.recording.path}\nClick to stop` : 'Record session'}
+        className={clsx(buttonClass, status.recording && "text-red-500 dark:text-red-400 animate-pulse", status.replay && "opacity-40")}
+      >
+        <LuDisc />
+      </button>
+      <button onClick={toggleMenu} title="Recorded sessions" className={buttonClass}>
+        <LuArchive />
+      </button>
+
+      {menuOpen && (
+        <div className="absolute right-0 top-full mt-2 w-96 z-50 rounded-md shadow-lg border border-sci-border-light dark:border-sci-border-dark bg-sci-bg-light-alt dark:bg-sci-bg-dark-alt text-sm">
+          <div className="px-3 py-2 border-b border-sci-border-light dark:border-sci-border-dark flex items-center justify-between text-xs text-sci-text-light-secondary dark:text-sci-text-dark-secondary">
+            <span>Recorded sessions</span>
+            <label className="flex items-center gap-1.5">
+              Speed
+              <select
+                value={speed}
+                onChange={(e) => setSpeed(Number(e.target.value))}
+                className="rounded border border-sci-border-light dark:border-sci-border-dark bg-sci-bg-light dark:bg-sci-bg-dark px-1"
+              >
+                {REPLAY_SPEEDS.map((s) => <option key={s} value={s}>{s}x</option>)}
+              </select>
+            </label>
+          </div>
+          <div className="max-h-80 overflow-auto">
+            {sessions.length === 0 && (
+              <div className="p-3 text-xs text-sci-text-light-secondary dark:text-sci-text-dark-secondary">No recorded sessions yet.</div>
+            )}
+            {sessions.map((session) => (
+              <button
+                key={session.path}
+                onClick={() => startReplay(session.path)}
+                disabled={!session.header || !!status.recording}
+                title={session.path}
+                className="w-full px-3 py-2 flex items-center gap-2 text-left hover:bg-sci-bg-light hover:dark:bg-sci-bg-dark disabled:opacity-50"
+              >
+                <LuChevronRight className="shrink-0 text-purple-500" />
+                <span className="flex-grow min-w-0">
+                  <span className="block truncate text-sci-text-light dark:text-sci-text-dark">{fileName(session.path)}</span>
+                  <span className="block text-xs text-sci-text-light-secondary dark:text-sci-text-dark-secondary">
+                    {session.header
+                      ? `${new Date(session.header.started).toLocaleString()} · ${session.header.platform} · ${session.header.interval_ms} ms`
+                      : 'Not a readable session'}
+                  </span>
+                </span>
+                <span className="text-xs font-mono text-sci-text-light-secondary dark:text-sci-text-dark-secondary">{formatBytes(session.size_bytes)}</span>
+              </button>
+            ))}
+          </div>
+        </div>
+      )}
+    </div>
+  );
+}
+
+export default SessionControls;
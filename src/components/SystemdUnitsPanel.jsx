@@ -0,0 +1,165 @@
+import React, { useState } from 'react';
+import { useSelector } from 'react-redux';
+import { invoke } from '@tauri-apps/api/core';
+import clsx from 'clsx';
+import { LuChevronDown, LuChevronRight } from "react-icons/lu";
+import { getCpuColorClass, formatPercent, formatSpeed } from '../utils/formatting';
+
+const TYPE_FILTERS = [
+  ['all', 'All'],
+  ['service', 'Services'],
+  ['scope', 'Scopes'],
+  ['session', 'Sessions'],
+];
+
+const formatMB = (mb) => (mb >= 1024 ? `${(mb / 1024).toFixed(2)} GB` : `${mb.toFixed(0)} MB`);
+
+const stateColorClass = (state) => {
+  if (state === 'active') return 'text-green-600 dark:text-green-400';
+  if (state === 'frozen') return 'text-blue-600 dark:text-blue-400';
+  return 'text-sci-text-light-secondary dark:text-sci-text-dark-secondary';
+};
+
+function SystemdUnitsPanel({ onContextMenu }) {
+  const { systemd_units: units } = useSelector((state) => state.systemData);
+  const [typeFilter, setTypeFilter] = useState('all');
+  const [expanded, setExpanded] = useState(null); // cgroup path of the unit showing its processes
+  const [unitProcesses, setUnitProcesses] = useState([]);
+  const [error, setError] = useState(null);
+
+  if (!units || units.length === 0) {
+    return (
+      <div className="p-4 text-sm text-sci-text-light-secondary dark:text-sci-text-dark-secondary">
+        No systemd units found in the cgroup hierarchy.
+      </div>
+    );
+  }
+
+  // Processes are fetched when a unit is opened rather than shipped with every update
+  const toggle = (unit) => {
+    if (expanded === unit.cgroup_path) {
+      setExpanded(null);
+      return;
+    }
+    setExpanded(unit.cgroup_path);
+    setUnitProcesses([]);
+    invoke('get_unit_processes', { cgroupPath: unit.cgroup_path })
+      .then((result) => { setUnitProcesses(result); setError(null); })
+      .catch((e) => setError(`${e}`));
+  };
+
+  // Busiest first within each slice; slices ordered by their total CPU
+  const visible = units.filter((u) => typeFilter === 'all' || u.unit_type === typeFilter);
+  const bySlice = new Map();
+  for (const unit of visible) {
+    const slice = unit.slice ?? '-.slice';
+    if (!bySlice.has(slice)) bySlice.set(slice, []);
+    bySlice.get(slice).push(unit);
+  }
+  const sliceCpu = (list) => list.reduce((sum, u) => sum + u.cpu_usage, 0);
+  const groups = [...bySlice.entries()]
+    .map(([slice, list]) => [slice, [...list].sort((a, b) => b.cpu_usage - a.cpu_usage)])
+    .sort((a, b) => sliceCpu(b[1]) - sliceCpu(a[1]));
+
+  return (
+    <div>
+      <div className="px-4 py-2 flex gap-1 text-xs">
+        {TYPE_FILTERS.map(([type, label]) => (
+          <button
+            key={type}
+            onClick={() => setTypeFilter(type)}
+            className={clsx(
+              'px-2 py-0.5 rounded border border-sci-border-light dark:border-sci-border-dark',
+              typeFilter === type
+                ? 'text-purple-600 dark:text-purple-400 bg-purple-50 dark:bg-purple-900/20'
+                : 'text-sci-text-light-secondary dark:text-sci-text-dark-secondary'
+            )}
+          >
+            {label}
+          </button>
+        ))}
+      </div>
+      <div className="overflow-auto max-h-[600px]">
+        <table className="w-full">
+          <thead>
+            <tr className="border-b border-sci-border-light dark:border-sci-border-dark text-sm text-sci-text-light-secondary dark:text-sci-text-dark-secondary">
+              <th className="p-3 text-left font-medium">Unit</th>
+              <th className="p-3 text-left font-medium" title="From cgroup.events">State</th>
+              <th className="p-3 text-left font-medium">CPU</th>
+              <th className="p-3 text-left font-medium">Memory</th>
+              <th className="p-3 text-left font-medium">I/O read / write</th>
+              <th className="p-3 text-left font-medium" title="Processes / tasks (pids.current, includes threads)">Procs / tasks</th>
+            </tr>
+          </thead>
+          <tbody>
+            {groups.map(([slice, list]) => (
+              <React.Fragment key={slice}>
+                <tr className="bg-sci-bg-light dark:bg-sci-bg-dark text-xs font-semibold text-sci-text-light-secondary dark:text-sci-text-dark-secondary">
+                  <td className="px-3 py-1" colSpan={6}>
+                    {slice} · {list.length} {list.length === 1 ? 'unit' : 'units'} · {formatPercent(sliceCpu(list))}
+                  </td>
+                </tr>
+                {list.map((unit) => (
+                  <React.Fragment key={unit.cgroup_path}>
+                    <tr
+                      onClick={() => toggle(unit)}
+                      className="border-b border-sci-border-light dark:border-sci-border-dark hover:bg-sci-bg-light hover:dark:bg-sci-bg-dark text-sm cursor-pointer"
+                    >
+                      <td className="p-2 text-sci-text-light dark:text-sci-text-dark">
+                        <div className="flex items-center gap-1.5" title={unit.cgroup_path}>
+                          {expanded === unit.cgroup_path ? <LuChevronDown className="w-3.5 h-3.5" /> : <LuChevronRight className="w-3.5 h-3.5" />}
+                          <span className="truncate max-w-xs">{unit.name}</span>
+                          {unit.owner_uid != null && (
+                            <span className="text-xs text-sci-text-light-secondary dark:text-sci-text-dark-secondary">uid {unit.owner_uid}</span>
+                          )}
+                        </div>
+                      </td>
+                      <td className={clsx('p-2 text-xs', stateColorClass(unit.active_state))}>{unit.active_state}</td>
+                      <td className={clsx('p-2 font-mono', getCpuColorClass(unit.cpu_usage))}>{formatPercent(unit.cpu_usage)}</td>
+                      <td className="p-2 font-mono text-sci-text-light dark:text-sci-text-dark">
+                        {unit.memory_mb != null ? formatMB(unit.memory_mb) : '—'}
+                      </td>
+                      <td className="p-2 font-mono text-sci-text-light dark:text-sci-text-dark">
+                        {formatSpeed(unit.io_read_kbps)} / {formatSpeed(unit.io_write_kbps)}
+                      </td>
+                      <td className="p-2 font-mono text-sci-text-light dark:text-sci-text-dark">
+                        {unit.process_count} / {unit.tasks ?? '—'}
+                      </td>
+                    </tr>
+                    {expanded === unit.cgroup_path && (
+                      <tr className="border-b border-sci-border-light dark:border-sci-border-dark">
+                        <td colSpan={6} className="px-8 py-2 text-xs">
+                          {error ? (
+                            <span className="text-red-500">Failed to load processes: {error}</span>
+                          ) : unitProcesses.length === 0 ? (
+                            <span className="text-sci-text-light-secondary dark:text-sci-text-dark-secondary">No processes</span>
+                          ) : (
+                            unitProcesses.map((p) => (
+                              <div
+                                key={p.pid}
+                                onContextMenu={(e) => onContextMenu?.(e, [p.pid, p.name, p.cpu_usage, Math.round(p.memory_mb)])}
+                                className="flex gap-4 font-mono py-0.5 text-sci-text-light dark:text-sci-text-dark"
+                              >
+                                <span className="w-16 text-right">{p.pid}</span>
+                                <span className="w-40 truncate" title={p.cmd || p.name}>{p.name}</span>
+                                <span className={clsx('w-16', getCpuColorClass(p.cpu_usage))}>{formatPercent(p.cpu_usage)}</span>
+                                <span className="w-20">{formatMB(p.memory_mb)}</span>
+                                <span className="text-sci-text-light-secondary dark:text-sci-text-dark-secondary">{p.user ?? ''}</span>
+                              </div>
+                            ))
+                          )}
+                        </td>
+                      </tr>
+                    )}
+                  </React.Fragment>
+                ))}
+              </React.Fragment>
+            ))}
+          </tbody>
+        </table>
+      </div>
+    </div>
+  );
+}
+
+export default SystemdUnitsPanel;
@@ -0,0 +1,36 @@
+// TauriViteReact/src/features/alerts/alertsSlice.js
+
+import { createSlice } from '@reduxjs/toolkit';
+
+// Alerts are keyed by rule + label, matching how the backend tracks them
+const alertKey = (alert) => `${alert.rule_id}/${alert.label}`;
+
+const initialState = {
+  // Currently firing alerts (payloads of 'alert-fired' / get_active_alerts)
+  active: [],
+};
+
+export const alertsSlice = createSlice({
+  name: 'alerts',
+  initialState,
+  reducers: {
+    setActiveAlerts: (state, action) => {
+      state.active = action.payload;
+    },
+    alertFired: (state, action) => {
+      const key = alertKey(action.payload);
+      state.active = state.active.filter((alert) => alertKey(alert) !== key);
+      state.active.push(action.payload);
+    },
+    alertResolved: (state, action) => {
+      const key = alertKey(action.payload);
+      state.active = state.active.filter((alert) => alertKey(alert) !== key);
+    },
+  },
+});
+
+// Export action creators
+export const { setActiveAlerts, alertFired, alertResolved } = alertsSlice.actions;
+
+// Export the reducer
+export default alertsSlice.reducer;
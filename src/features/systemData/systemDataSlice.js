@@ -5,14 +5,26 @@ import { createSlice } from '@reduxjs/toolkit';
 const initialState = {
   cpu_usage: [],
   cpu_history: [],
+  cpu_history_timestamps: [], // Epoch ms of each cpu_history sample
+  cpu_info: null, // Model, per-core frequency scaling, load average, uptime
+  cpu_times: null, // /proc/stat time categories (aggregate + per core) with aggregate history
   memory_used: 0.0,
   memory_total: 1.0, // Initial default to avoid division by zero
   memory_history: [],
+  memory_history_timestamps: [], // Epoch ms of each memory_history sample
+  memory_details: null, // /proc/meminfo breakdown with per-field histories (Linux)
   top_processes: [], // Expects Array of [pid_u32, name_string, cpu_f32, mem_mb_u64]
   network_data: {},  // Expects { interface_name: NetworkData, ... }
   gpu_data: [],      // Expects Array of GpuData
   disk_data: {},     // Expects { disk_name: DiskData, ... }
+  sensors: { chips: [], thermal_zones: [] }, // hwmon chips + thermal zones (Linux)
+  cgroups: [], // cgroup v2 groups with usage, parents first (Linux)
+  systemd_units: [], // Services and scopes from the cgroup tree (Linux)
+  cpu_power: null, // RAPL domains and package power (Linux, needs readable energy_uj)
+  power: null, // AC state and batteries (Linux)
+  pressure: null, // PSI, system-wide and per top-level cgroup (Linux 4.20+)
   timestamp: null,   // Expects ISO string or similar from backend
+  update_interval_ms: 1000,
   platform_name: 'Loading...',
   status: 'idle',    // 'idle' | 'loading' | 'succeeded' | 'failed'
   error: null,       // Stores error messages related to data fetching/processing
@@ -32,14 +44,26 @@ export const systemDataSlice = createSlice({
       // Replace state fields with new data from backend payload
       state.cpu_usage = newData.cpu_usage;
       state.cpu_history = newData.cpu_history;
+      state.cpu_history_timestamps = newData.cpu_history_timestamps ?? [];
+      state.cpu_info = newData.cpu_info ?? null;
+      state.cpu_times = newData.cpu_times ?? null;
       state.memory_used = newData.memory_used;
       state.memory_total = newData.memory_total;
       state.memory_history = newData.memory_history;
+      state.memory_history_timestamps = newData.memory_history_timestamps ?? [];
+      state.memory_details = newData.memory_details ?? null;
       state.top_processes = newData.top_processes;
       state.network_data = newData.network_data;
       state.gpu_data = newData.gpu_data;
       state.disk_data = newData.disk_data;
+      state.sensors = newData.sensors ?? initialState.sensors;
+      state.cgroups = newData.cgroups ?? [];
+      state.systemd_units = newData.systemd_units ?? [];
+      state.pressure = newData.pressure ?? null;
+      state.power = newData.power ?? null;
+      state.cpu_power = newData.cpu_power ?? null;
       state.timestamp = newData.timestamp; // Assumes backend sends a serializable format
+      state.update_interval_ms = newData.update_interval_ms ?? state.update_interval_ms;
       state.platform_name = newData.platform_name;
       state.status = 'succeeded';
       state.error = null; // Clear error on successful update
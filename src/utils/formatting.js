@@ -170,4 +170,34 @@ export function formatTimeForChart(timestamp) {
         second: '2-digit',
         hour12: false
     });
-}
\ No newline at end of file
+}
+/**
+ * Build chart timestamps for a history series of the given length.
+ * Uses the series' own sample times (every history carries its history_timestamps) so
+ * labels stay correct when the sampling interval changes or the series started late;
+ * older points without a recorded time are extrapolated backwards using the current interval.
+ * @param {number[]} historyTimestamps Epoch ms of each sample of that series, oldest first.
+ * @param {number} length Number of points in the series being charted.
+ * @param {number} intervalMs Current sampling interval in ms (default: 1000).
+ * @returns {number[]} Epoch ms timestamps, oldest first.
+ */
+export function buildHistoryTimestamps(historyTimestamps, length, intervalMs = 1000) {
+    const known = Array.isArray(historyTimestamps) ? historyTimestamps.slice(-length) : [];
+    const step = intervalMs > 0 ? intervalMs : 1000;
+    const oldest = known.length > 0 ? known[0] : Date.now() + step;
+    const missing = Math.max(0, length - known.length);
+    const padding = Array.from({ length: missing }, (_, i) => oldest - (missing - i) * step);
+    return padding.concat(known);
+}
+
+/**
+ * Format a duration in seconds as e.g. "2d 3h 4m".
+ * @param {number} seconds Duration in seconds.
+ * @returns {string} Formatted duration.
+ */
+export function formatDuration(seconds) {
+    const days = Math.floor(seconds / 86400);
+    const hours = Math.floor((seconds % 86400) / 3600);
+    const minutes = Math.floor((seconds % 3600) / 60);
+    return [days && `${days}d`, (days || hours) && `${hours}h`, `${minutes}m`].filter(Boolean).join(' ');
+}
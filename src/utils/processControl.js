@@ -0,0 +1,30 @@
+// src/utils/processControl.js
+import { invoke } from '@tauri-apps/api/core';
+
+// Human-readable text for a ProcessActionResult returned by the backend
+export const describeProcessAction = (name, result) => {
+  const label = `${name} (PID ${result.pid})`;
+  switch (result.outcome?.status) {
+    case 'signaled': return `Sent ${result.signal} to ${label}.`;
+    case 'exited': return `${label} exited.`;
+    case 'killed': return `${label} did not respond to SIGTERM and was killed.`;
+    case 'still_running': return `${label} is still running after SIGKILL.`;
+    case 'permission_denied': return `Permission denied: cannot signal ${label}.`;
+    case 'no_such_process': return `${label} is no longer running.`;
+    case 'pid_reused': return `PID ${result.pid} now belongs to "${result.outcome.actual_name}"; nothing was sent.`;
+    case 'invalid_signal': return `Invalid signal "${result.outcome.signal}".`;
+    case 'unsupported':
+    case 'failed': return `Could not signal ${label}: ${result.outcome.message}`;
+    default: return `Unexpected result for ${label}.`;
+  }
+};
+
+// Graceful terminate (SIGTERM, escalating to SIGKILL) guarded by the expected name
+export const terminateProcess = async (pid, name) => {
+  try {
+    const result = await invoke('terminate_process', { pid, expectedName: name });
+    return describeProcessAction(name, result);
+  } catch (error) {
+    return `Failed to terminate ${name} (PID ${pid}): ${error}`;
+  }
+};